@@ -20,7 +20,7 @@ else
 fi
 
 HOST_TRIPLE=$(rustc -vV | grep host | cut -d: -f2 | tr -d " ")
-TARGET_TRIPLE=$HOST_TRIPLE
+TARGET_TRIPLE=${TARGET_TRIPLE:-$HOST_TRIPLE}
 #TARGET_TRIPLE="m68k-unknown-linux-gnu"
 
 linker=''
@@ -33,6 +33,29 @@ if [[ "$HOST_TRIPLE" != "$TARGET_TRIPLE" ]]; then
       # We are cross-compiling for aarch64. Use the correct linker and run tests in qemu.
       linker='-Clinker=aarch64-linux-gnu-gcc'
       RUN_WRAPPER='qemu-aarch64 -L /usr/aarch64-linux-gnu'
+   elif [[ "$TARGET_TRIPLE" == "armv7-unknown-linux-gnueabihf" ]]; then
+      # We are cross-compiling for armv7. Use the correct linker and run tests in qemu.
+      linker='-Clinker=arm-linux-gnueabihf-gcc'
+      RUN_WRAPPER='qemu-arm -L /usr/arm-linux-gnueabihf'
+   elif [[ "$TARGET_TRIPLE" == "riscv64gc-unknown-linux-gnu" ]]; then
+      # We are cross-compiling for riscv64. Use the correct linker and run tests in qemu.
+      linker='-Clinker=riscv64-linux-gnu-gcc'
+      RUN_WRAPPER='qemu-riscv64 -L /usr/riscv64-linux-gnu'
+   elif [[ "$TARGET_TRIPLE" == "mips-unknown-linux-gnu" ]]; then
+      # We are cross-compiling for big-endian mips. Use the correct linker and run tests in qemu.
+      linker='-Clinker=mips-linux-gnu-gcc'
+      RUN_WRAPPER='qemu-mips -L /usr/mips-linux-gnu'
+   elif [[ "$TARGET_TRIPLE" == "powerpc-unknown-linux-gnu" ]]; then
+      # We are cross-compiling for big-endian powerpc. Use the correct linker and run tests in qemu.
+      linker='-Clinker=powerpc-linux-gnu-gcc'
+      RUN_WRAPPER='qemu-ppc -L /usr/powerpc-linux-gnu'
+   elif [[ "$TARGET_TRIPLE" == "s390x-unknown-linux-gnu" ]]; then
+      # We are cross-compiling for s390x, which is big-endian. Use the correct linker and run tests in qemu.
+      linker='-Clinker=s390x-linux-gnu-gcc'
+      RUN_WRAPPER='qemu-s390x -L /usr/s390x-linux-gnu'
+   elif [[ "$TARGET_TRIPLE" == "i686-unknown-linux-gnu" ]]; then
+      # A multilib libgccjit and the system linker can target i686 from x86-64 and the tests run natively.
+      :
    else
       echo "Unknown non-native platform"
    fi
@@ -45,6 +68,11 @@ if [[ `uname` == 'Darwin' ]]; then
    export RUSTFLAGS="$RUSTFLAGS -Clink-arg=-undefined -Clink-arg=dynamic_lookup"
 fi
 
+# NOTE: the 16-byte atomic loads are done in libatomic.
+if [[ "$TARGET_TRIPLE" == x86_64-*-linux-gnu ]]; then
+   export RUSTFLAGS="$RUSTFLAGS -Clink-arg=-latomic"
+fi
+
 RUSTC="rustc $RUSTFLAGS -L crate=target/out --out-dir target/out"
 export RUSTC_LOG=warn # display metadata load errors
 
@@ -129,6 +129,9 @@ impl<'gcc, 'tcx> FnAbiGccExt<'gcc, 'tcx> for FnAbi<'tcx, Ty<'tcx>> {
             } + args_capacity,
         );
 
+        // TODO(antoyo): map the NoAlias, NonNull and ReadOnly attributes of the pointer arguments to
+        // restrict, nonnull and access, and use their dereferenceable size and alignment, when
+        // libgccjit supports attributes on parameters.
         let return_ty =
             match self.ret.mode {
                 PassMode::Ignore => cx.type_void(),
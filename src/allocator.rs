@@ -6,9 +6,12 @@ use rustc_session::config::OomStrategy;
 use rustc_span::symbol::sym;
 
 use crate::GccContext;
+use crate::base;
 
 pub(crate) unsafe fn codegen(tcx: TyCtxt<'_>, mods: &mut GccContext, _module_name: &str, kind: AllocatorKind, has_alloc_error_handler: bool) {
     let context = &mods.context;
+    base::add_hardening_options(context, tcx.sess);
+    base::add_frame_options(context, tcx.sess);
     let usize =
         match tcx.sess.target.pointer_width {
             16 => context.new_type::<u16>(),
@@ -52,9 +55,6 @@ pub(crate) unsafe fn codegen(tcx: TyCtxt<'_>, mods: &mut GccContext, _module_nam
         if tcx.sess.target.options.default_hidden_visibility {
             // TODO(antoyo): set visibility.
         }
-        if tcx.sess.must_emit_unwind_tables() {
-            // TODO(antoyo): emit unwind tables.
-        }
 
         let callee = kind.fn_name(method.name);
         let args: Vec<_> = types.iter().enumerate()
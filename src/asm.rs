@@ -1,10 +1,16 @@
 use gccjit::{LValue, RValue, ToRValue, Type};
 use rustc_ast::ast::{InlineAsmOptions, InlineAsmTemplatePiece};
+use rustc_codegen_ssa::common;
 use rustc_codegen_ssa::mir::operand::OperandValue;
 use rustc_codegen_ssa::mir::place::PlaceRef;
-use rustc_codegen_ssa::traits::{AsmBuilderMethods, AsmMethods, BaseTypeMethods, BuilderMethods, GlobalAsmOperandRef, InlineAsmOperandRef};
-
-use rustc_middle::{bug, ty::Instance};
+use rustc_codegen_ssa::traits::{AsmBuilderMethods, AsmMethods, BaseTypeMethods, BuilderMethods, GlobalAsmOperandRef, InlineAsmOperandRef, MiscMethods};
+
+use rustc_middle::{bug, span_bug};
+use rustc_middle::mir::{self, InlineAsmOperand, TerminatorKind};
+use rustc_middle::mir::interpret::ConstValue;
+use rustc_middle::mir::mono::Linkage;
+use rustc_middle::ty::{self, Instance};
+use rustc_middle::ty::layout::LayoutOf;
 use rustc_span::Span;
 use rustc_target::asm::*;
 
@@ -12,6 +18,7 @@ use std::borrow::Cow;
 
 use crate::builder::Builder;
 use crate::context::CodegenCx;
+use crate::declare::mangle_name;
 use crate::type_of::LayoutGccExt;
 
 
@@ -60,8 +67,11 @@ use crate::type_of::LayoutGccExt;
 //                                      where "tmp" is a temporary unused variable
 //
 // * `out/in/inout("explicit register") var` -> translated to one or two operands as described above
-//                                              with `"r"(var)` constraint,
+//                                              with the constraint of the register's class (e.g. `"r"(var)`),
 //                                              and one register variable assigned to the desired register.
+//
+// * `sym symbol` -> the symbol name is injected into the template, and the address of the symbol
+//                   is added as an unused input operand: `"X"(&symbol)`
 
 const ATT_SYNTAX_INS: &str = ".att_syntax noprefix\n\t";
 const INTEL_SYNTAX_INS: &str = "\n\t.intel_syntax noprefix";
@@ -105,18 +115,38 @@ enum ConstraintOrRegister {
 
 
 impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
-    fn codegen_inline_asm(&mut self, template: &[InlineAsmTemplatePiece], rust_operands: &[InlineAsmOperandRef<'tcx, Self>], options: InlineAsmOptions, span: &[Span], _instance: Instance<'_>, _dest_catch_funclet: Option<(Self::BasicBlock, Self::BasicBlock, Option<&Self::Funclet>)>) {
-        if options.contains(InlineAsmOptions::MAY_UNWIND) {
-            self.sess()
-                .struct_span_err(span[0], "GCC backend does not support unwinding from inline asm")
-                .emit();
-            return;
+    fn codegen_inline_asm(&mut self, template: &[InlineAsmTemplatePiece], rust_operands: &[InlineAsmOperandRef<'tcx, Self>], options: InlineAsmOptions, span: &[Span], _instance: Instance<'_>, dest_catch_funclet: Option<(Self::BasicBlock, Self::BasicBlock, Option<&Self::Funclet>)>) {
+        // NOTE: report the registers and register classes that GCC has no equivalent for instead
+        // of panicking.
+        for op in rust_operands {
+            if let InlineAsmOperandRef::In { reg, .. }
+                | InlineAsmOperandRef::Out { reg, .. }
+                | InlineAsmOperandRef::InOut { reg, .. } = *op
+            {
+                if is_unknown_register_clobber(op) {
+                    continue;
+                }
+                let unsupported =
+                    match reg {
+                        InlineAsmRegOrRegClass::Reg(reg) => {
+                            explicit_reg_to_gcc(reg).is_none().then(|| format!("register `{}`", reg.name()))
+                        },
+                        InlineAsmRegOrRegClass::RegClass(reg_class) => {
+                            reg_class_to_gcc(reg_class).is_none().then(|| format!("register class `{}`", reg_class.name()))
+                        },
+                    };
+                if let Some(unsupported) = unsupported {
+                    self.sess()
+                        .struct_span_err(span[0], &format!("GCC backend does not support the {} in inline asm", unsupported))
+                        .emit();
+                    return;
+                }
+            }
         }
 
         let asm_arch = self.tcx.sess.asm_arch.unwrap();
         let is_x86 = matches!(asm_arch, InlineAsmArch::X86 | InlineAsmArch::X86_64);
         let att_dialect = is_x86 && options.contains(InlineAsmOptions::ATT_SYNTAX);
-        let intel_dialect = is_x86 && !options.contains(InlineAsmOptions::ATT_SYNTAX);
 
         // GCC index of an output operand equals its position in the array
         let mut outputs = vec![];
@@ -152,6 +182,8 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
         // 1. Normal variables (and saving operands to buffers).
         for (rust_idx, op) in rust_operands.iter().enumerate() {
             match *op {
+                _ if is_unknown_register_clobber(op) => continue,
+
                 InlineAsmOperandRef::Out { reg, late, place } => {
                     use ConstraintOrRegister::*;
 
@@ -168,8 +200,11 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
                         },
                         (Register(reg_name), None) => {
                             // `clobber_abi` can add lots of clobbers that are not supported by the target,
-                            // such as AVX-512 registers, so we just ignore unsupported registers
-                            let is_target_supported = reg.reg_class().supported_types(asm_arch).iter()
+                            // such as AVX-512 registers, so we just ignore unsupported registers.
+                            // Clobber-only register classes (like x87 and MMX) have no supported types
+                            // and are always kept.
+                            let supported_types = reg.reg_class().supported_types(asm_arch);
+                            let is_target_supported = supported_types.is_empty() || supported_types.iter()
                                 .any(|&(_, feature)| {
                                     if let Some(feature) = feature {
                                         self.tcx.sess.target_features.contains(&feature)
@@ -257,11 +292,24 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
                     constants_len += string.len() + att_dialect as usize;
                 }
 
+                // NOTE: the symbol only appears in the template, so GCC would not know it is used and
+                // could remove it if it is internal to this codegen unit. Add its address as an
+                // unused input operand that accepts anything to keep it alive.
                 InlineAsmOperandRef::SymFn { instance } => {
+                    inputs.push(AsmInOperand {
+                        constraint: Cow::Borrowed("X"),
+                        rust_idx,
+                        val: self.cx.get_fn_addr(instance),
+                    });
                     constants_len += self.tcx.symbol_name(instance).name.len();
                 }
                 InlineAsmOperandRef::SymStatic { def_id } => {
-                    constants_len += self.tcx.symbol_name(Instance::mono(self.tcx, def_id)).name.len();
+                    inputs.push(AsmInOperand {
+                        constraint: Cow::Borrowed("X"),
+                        rust_idx,
+                        val: self.cx.get_static(def_id).get_address(None),
+                    });
+                    constants_len += mangle_name(self.tcx.symbol_name(Instance::mono(self.tcx, def_id)).name).len();
                 }
             }
         }
@@ -269,6 +317,8 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
         // 2. Register variables.
         for (rust_idx, op) in rust_operands.iter().enumerate() {
             match *op {
+                _ if is_unknown_register_clobber(op) => continue,
+
                 // `out("explicit register") var`
                 InlineAsmOperandRef::Out { reg, late, place } => {
                     if let ConstraintOrRegister::Register(reg_name) = reg_to_gcc(reg) {
@@ -285,7 +335,7 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
                         tmp_var.set_register_name(reg_name);
 
                         outputs.push(AsmOutOperand {
-                            constraint: "r".into(),
+                            constraint: register_constraint(reg),
                             rust_idx,
                             late,
                             readwrite: false,
@@ -306,7 +356,7 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
                         self.llbb().add_assignment(None, reg_var, value.immediate());
 
                         inputs.push(AsmInOperand {
-                            constraint: "r".into(),
+                            constraint: register_constraint(reg).into(),
                             rust_idx,
                             val: reg_var.to_rvalue()
                         });
@@ -324,7 +374,7 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
                         tmp_var.set_register_name(reg_name);
 
                         outputs.push(AsmOutOperand {
-                            constraint: "r".into(),
+                            constraint: register_constraint(reg),
                             rust_idx,
                             late,
                             readwrite: false,
@@ -351,10 +401,15 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
             }
         }
 
+        // NOTE: the red zone is not used in the codegen units containing an asm without nostack,
+        // see base::module_codegen().
+        // TODO(antoyo): like LLVM's alignstack, make sure the stack is aligned for a call in such
+        // an asm: GCC does not guarantee it in leaf functions.
+
         // 3. Build the template string
 
         let mut template_str = String::with_capacity(estimate_template_length(template, constants_len, att_dialect));
-        if !intel_dialect {
+        if att_dialect {
             template_str.push_str(ATT_SYNTAX_INS);
         }
 
@@ -412,16 +467,18 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
                         }
 
                         InlineAsmOperandRef::SymFn { instance } => {
-                            let name = self.tcx.symbol_name(instance).name;
-                            template_str.push_str(name);
+                            // NOTE: the function is declared with this name in declare_raw_fn().
+                            let name = mangle_name(self.tcx.symbol_name(instance).name);
+                            template_str.push_str(&name);
                         }
 
                         InlineAsmOperandRef::SymStatic { def_id } => {
                             // TODO(@Commeownist): This may not be sufficient for all kinds of statics.
                             // Some statics may need the `@plt` suffix, like thread-local vars.
+                            // NOTE: the static is declared with this name in declare_global().
                             let instance = Instance::mono(self.tcx, def_id);
-                            let name = self.tcx.symbol_name(instance).name;
-                            template_str.push_str(name);
+                            let name = mangle_name(self.tcx.symbol_name(instance).name);
+                            template_str.push_str(&name);
                         }
 
                         InlineAsmOperandRef::Const { ref string } => {
@@ -436,7 +493,7 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
             }
         }
 
-        if !intel_dialect {
+        if att_dialect {
             template_str.push_str(INTEL_SYNTAX_INS);
         }
 
@@ -457,20 +514,23 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
             extended_asm.add_clobber(clobber);
         }
 
+        // NOTE: "cc" is the flags register (and the x87 status word on x86) on every architecture
+        // GCC supports. The x87 and MMX registers themselves are clobbered by `clobber_abi` or
+        // explicit `out("st(0)") _` operands.
         if !options.contains(InlineAsmOptions::PRESERVES_FLAGS) {
-            // TODO(@Commeownist): I'm not 100% sure this one clobber is sufficient
-            // on all architectures. For instance, what about FP stack?
             extended_asm.add_clobber("cc");
         }
+        // NOTE: GCC has no clobber for an asm that only reads memory, so readonly asm also gets the
+        // memory clobber: this is conservative, but it prevents moving the asm across stores.
         if !options.contains(InlineAsmOptions::NOMEM) {
             extended_asm.add_clobber("memory");
         }
+        // NOTE: a pure asm is a non-volatile asm in GCC: it can be removed if its outputs are unused,
+        // and deduplicated when it has the same inputs (without a memory write in between, thanks to
+        // the memory clobber, for readonly asm).
         if !options.contains(InlineAsmOptions::PURE) {
             extended_asm.set_volatile_flag(true);
         }
-        if !options.contains(InlineAsmOptions::NOSTACK) {
-            // TODO(@Commeownist): figure out how to align stack
-        }
         if options.contains(InlineAsmOptions::NORETURN) {
             let builtin_unreachable = self.context.get_builtin_function("__builtin_unreachable");
             let builtin_unreachable: RValue<'gcc> = unsafe { std::mem::transmute(builtin_unreachable) };
@@ -492,6 +552,11 @@ impl<'a, 'gcc, 'tcx> AsmBuilderMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
             }
         }
 
+        // An asm that may unwind is a terminator with a landing pad, like an invoke.
+        if let Some((then, _catch, _funclet)) = dest_catch_funclet {
+            // TODO(bjorn3): Properly implement unwinding.
+            self.llbb().end_with_jump(None, then);
+        }
     }
 }
 
@@ -520,81 +585,188 @@ fn estimate_template_length(template: &[InlineAsmTemplatePiece], constants_len:
     res
 }
 
-/// Converts a register class to a GCC constraint code.
+/// Converts a register or a register class to a GCC register name or constraint code.
 fn reg_to_gcc(reg: InlineAsmRegOrRegClass) -> ConstraintOrRegister {
-    let constraint = match reg {
-        // For vector registers LLVM wants the register name to match the type size.
+    match reg {
+        // For explicit registers, we have to create a register variable: https://stackoverflow.com/a/31774784/389119
         InlineAsmRegOrRegClass::Reg(reg) => {
-            match reg {
-                InlineAsmReg::X86(_) => {
-                    // TODO(antoyo): add support for vector register.
-                    //
-                    // // For explicit registers, we have to create a register variable: https://stackoverflow.com/a/31774784/389119
-                    return ConstraintOrRegister::Register(match reg.name() {
-                        // Some of registers' names does not map 1-1 from rust to gcc
-                        "st(0)" => "st",
+            ConstraintOrRegister::Register(explicit_reg_to_gcc(reg).expect("unsupported register"))
+        },
+        InlineAsmRegOrRegClass::RegClass(reg) => {
+            ConstraintOrRegister::Constraint(reg_class_to_gcc(reg).expect("unsupported register class"))
+        },
+    }
+}
 
-                        name => name,
-                    });
+// NOTE: GCC only knows the floating-point registers of PowerPC as fr0-fr31 and 0-31.
+const POWERPC_FLOAT_REGISTERS: [&str; 32] = [
+    "fr0", "fr1", "fr2", "fr3", "fr4", "fr5", "fr6", "fr7", "fr8", "fr9", "fr10", "fr11", "fr12",
+    "fr13", "fr14", "fr15", "fr16", "fr17", "fr18", "fr19", "fr20", "fr21", "fr22", "fr23", "fr24",
+    "fr25", "fr26", "fr27", "fr28", "fr29", "fr30", "fr31",
+];
+
+const X86_VECTOR_REGISTERS: [&str; 32] = [
+    "xmm0", "xmm1", "xmm2", "xmm3", "xmm4", "xmm5", "xmm6", "xmm7", "xmm8", "xmm9", "xmm10",
+    "xmm11", "xmm12", "xmm13", "xmm14", "xmm15", "xmm16", "xmm17", "xmm18", "xmm19", "xmm20",
+    "xmm21", "xmm22", "xmm23", "xmm24", "xmm25", "xmm26", "xmm27", "xmm28", "xmm29", "xmm30",
+    "xmm31",
+];
+
+/// Whether the operand clobbers an explicit register that GCC does not know, like the RISC-V vector
+/// registers clobbered by `clobber_abi`. GCC never allocates such a register, so the clobber is
+/// dropped.
+///
+/// NOTE: the BPF w registers are the lower halves of the r registers, which GCC allocates, so their
+/// clobbers are not dropped and are reported as unsupported.
+fn is_unknown_register_clobber(op: &InlineAsmOperandRef<'_, Builder<'_, '_, '_>>) -> bool {
+    match *op {
+        InlineAsmOperandRef::Out { reg: InlineAsmRegOrRegClass::Reg(reg), place: None, .. } => {
+            !matches!(reg, InlineAsmReg::Bpf(_)) && explicit_reg_to_gcc(reg).is_none()
+        },
+        _ => false,
+    }
+}
+
+/// Converts an explicit register to the name GCC knows it by, or `None` if GCC does not support it.
+fn explicit_reg_to_gcc(reg: InlineAsmReg) -> Option<&'static str> {
+    let name = reg.name();
+    let name =
+        match reg {
+            InlineAsmReg::X86(_) => {
+                match name {
+                    // Some of registers' names does not map 1-1 from rust to gcc
+                    "st(0)" => "st",
+
+                    // NOTE: GCC only knows the vector registers by their xmm name: the size of
+                    // the register is the size of the type of the register variable.
+                    name if name.starts_with("ymm") || name.starts_with("zmm") => {
+                        let number = name[3..].parse::<usize>().expect("vector register number");
+                        X86_VECTOR_REGISTERS[number]
+                    },
+
+                    name => name,
                 }
+            },
+            // TODO(antoyo): support the vector registers when GCC supports the vector extension.
+            InlineAsmReg::RiscV(_) if name.starts_with('v') => return None,
+            // NOTE: GCC only knows the BPF registers by their 64-bit r name and has no constraint
+            // for the 32-bit w subregisters (see reg_class_to_gcc()).
+            InlineAsmReg::Bpf(_) if name.starts_with('w') => return None,
+            // NOTE: the register names of these architectures are the same in GCC, or GCC accepts
+            // them as alternative names (e.g. x0-x31 and f0-f31 on RISC-V).
+            InlineAsmReg::AArch64(_)
+            | InlineAsmReg::Arm(_)
+            | InlineAsmReg::Bpf(_)
+            | InlineAsmReg::Mips(_)
+            | InlineAsmReg::Msp430(_)
+            | InlineAsmReg::RiscV(_)
+            // NOTE: GCC strips the % prefix of the s390x register names.
+            | InlineAsmReg::S390x(_) => name,
+            InlineAsmReg::PowerPC(_) => {
+                match name.strip_prefix('f').and_then(|number| number.parse::<usize>().ok()) {
+                    Some(number) => POWERPC_FLOAT_REGISTERS[number],
+                    None => name,
+                }
+            },
+            // NOTE: a pair of registers (e.g. r25r24 or X) is named after its low register in GCC.
+            InlineAsmReg::Avr(_) => {
+                match name {
+                    "X" => "r26",
+                    "Y" => "r28",
+                    "Z" => "r30",
+                    _ => &name[name.rfind('r').unwrap_or(0)..],
+                }
+            },
+            // NOTE: GCC has no Hexagon, WebAssembly or SPIR-V port, and its nvptx port is only
+            // used for offloading.
+            InlineAsmReg::Hexagon(_)
+            | InlineAsmReg::Nvptx(_)
+            | InlineAsmReg::SpirV(_)
+            | InlineAsmReg::Wasm(_) => return None,
+            InlineAsmReg::Err => unreachable!(),
+        };
+    Some(name)
+}
 
-                _ => unimplemented!(),
-            }
+/// Constraint code to use for the register variable of an explicit register.
+fn register_constraint(reg: InlineAsmRegOrRegClass) -> &'static str {
+    match reg.reg_class() {
+        // NOTE: the x constraint only covers xmm0-xmm15, but the register variable can be one of the
+        // AVX-512 registers xmm16-xmm31.
+        InlineAsmRegClass::X86(
+            X86InlineAsmRegClass::xmm_reg | X86InlineAsmRegClass::ymm_reg | X86InlineAsmRegClass::zmm_reg,
+        ) => "v",
+        InlineAsmRegClass::X86(X86InlineAsmRegClass::kreg) => "k",
+        reg_class => reg_class_to_gcc(reg_class).expect("unsupported register class"),
+    }
+}
+
+/// Converts a register class to a GCC constraint code, or `None` if GCC cannot allocate registers
+/// of this class.
+fn reg_class_to_gcc(reg: InlineAsmRegClass) -> Option<&'static str> {
+    let constraint = match reg {
+        InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::reg) => "r",
+        InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg) => "w",
+        InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg_low16) => "x",
+        InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::preg) => unreachable!("clobber-only"),
+        InlineAsmRegClass::Arm(ArmInlineAsmRegClass::reg) => "r",
+        // NOTE: s0-s31, which overlap d0-d15 and q0-q7.
+        InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg)
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg_low16)
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low8) => "t",
+        // NOTE: d0-d7, which overlap s0-s15 and q0-q3.
+        InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg_low16)
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg_low8)
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low4) => "x",
+        InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg)
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg) => "w",
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg) => "r",
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_upper) => "d",
+        // NOTE: GCC allocates a pair of registers for 16-bit values in the r constraint.
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_pair) => "r",
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_iw) => "w",
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_ptr) => "e",
+        InlineAsmRegClass::Bpf(BpfInlineAsmRegClass::reg) => "r",
+        // TODO(antoyo): support the 32-bit subregisters if GCC ever gets a constraint for them.
+        InlineAsmRegClass::Bpf(BpfInlineAsmRegClass::wreg) => return None,
+        InlineAsmRegClass::Hexagon(HexagonInlineAsmRegClass::reg) => return None,
+        InlineAsmRegClass::Mips(MipsInlineAsmRegClass::reg) => "r",
+        InlineAsmRegClass::Mips(MipsInlineAsmRegClass::freg) => "f",
+        InlineAsmRegClass::Msp430(Msp430InlineAsmRegClass::reg) => "r",
+        InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg16)
+        | InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg32)
+        | InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg64) => return None,
+        InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::reg) => "r",
+        InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::reg_nonzero) => "b",
+        InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::freg) => "f",
+        InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::cr)
+        | InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::xer) => {
+            unreachable!("clobber-only")
         },
-        InlineAsmRegOrRegClass::RegClass(reg) => match reg {
-            InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::preg) => unimplemented!(),
-            InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::reg) => unimplemented!(),
-            InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg) => unimplemented!(),
-            InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg_low16) => unimplemented!(),
-            InlineAsmRegClass::Arm(ArmInlineAsmRegClass::reg) => unimplemented!(),
-            InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg)
-            | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg_low16)
-            | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low8) => unimplemented!(),
-            InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg_low16)
-            | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg_low8)
-            | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low4) => unimplemented!(),
-            InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg)
-            | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg) => unimplemented!(),
-            InlineAsmRegClass::Avr(_) => unimplemented!(),
-            InlineAsmRegClass::Bpf(_) => unimplemented!(),
-            InlineAsmRegClass::Hexagon(HexagonInlineAsmRegClass::reg) => unimplemented!(),
-            InlineAsmRegClass::Mips(MipsInlineAsmRegClass::reg) => unimplemented!(),
-            InlineAsmRegClass::Mips(MipsInlineAsmRegClass::freg) => unimplemented!(),
-            InlineAsmRegClass::Msp430(_) => unimplemented!(),
-            InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg16) => unimplemented!(),
-            InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg32) => unimplemented!(),
-            InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg64) => unimplemented!(),
-            InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::reg) => unimplemented!(),
-            InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::reg_nonzero) => unimplemented!(),
-            InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::freg) => unimplemented!(),
-            InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::cr)
-            | InlineAsmRegClass::PowerPC(PowerPCInlineAsmRegClass::xer) => {
-                unreachable!("clobber-only")
-            },
-            InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::reg) => unimplemented!(),
-            InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::freg) => unimplemented!(),
-            InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::vreg) => unimplemented!(),
-            InlineAsmRegClass::X86(X86InlineAsmRegClass::reg) => "r",
-            InlineAsmRegClass::X86(X86InlineAsmRegClass::reg_abcd) => "Q",
-            InlineAsmRegClass::X86(X86InlineAsmRegClass::reg_byte) => "q",
-            InlineAsmRegClass::X86(X86InlineAsmRegClass::xmm_reg)
-            | InlineAsmRegClass::X86(X86InlineAsmRegClass::ymm_reg) => "x",
-            InlineAsmRegClass::X86(X86InlineAsmRegClass::zmm_reg) => "v",
-            InlineAsmRegClass::X86(X86InlineAsmRegClass::kreg) => unimplemented!(),
-            InlineAsmRegClass::Wasm(WasmInlineAsmRegClass::local) => unimplemented!(),
-            InlineAsmRegClass::X86(
-                X86InlineAsmRegClass::x87_reg | X86InlineAsmRegClass::mmx_reg,
-            ) => unreachable!("clobber-only"),
-            InlineAsmRegClass::SpirV(SpirVInlineAsmRegClass::reg) => {
-                bug!("GCC backend does not support SPIR-V")
-            }
-            InlineAsmRegClass::S390x(S390xInlineAsmRegClass::reg) => unimplemented!(),
-            InlineAsmRegClass::S390x(S390xInlineAsmRegClass::freg) => unimplemented!(),
-            InlineAsmRegClass::Err => unreachable!(),
-        }
+        InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::reg) => "r",
+        InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::freg) => "f",
+        // TODO(antoyo): use the vr constraint when GCC supports the vector extension.
+        InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::vreg) => return None,
+        InlineAsmRegClass::X86(X86InlineAsmRegClass::reg) => "r",
+        InlineAsmRegClass::X86(X86InlineAsmRegClass::reg_abcd) => "Q",
+        InlineAsmRegClass::X86(X86InlineAsmRegClass::reg_byte) => "q",
+        InlineAsmRegClass::X86(X86InlineAsmRegClass::xmm_reg)
+        | InlineAsmRegClass::X86(X86InlineAsmRegClass::ymm_reg) => "x",
+        InlineAsmRegClass::X86(X86InlineAsmRegClass::zmm_reg) => "v",
+        // NOTE: Yk excludes k0, which cannot be used as a mask since it means "no mask" in the
+        // encoding of AVX-512 instructions, like the kreg class of rustc.
+        InlineAsmRegClass::X86(X86InlineAsmRegClass::kreg) => "Yk",
+        InlineAsmRegClass::Wasm(WasmInlineAsmRegClass::local) => return None,
+        InlineAsmRegClass::X86(
+            X86InlineAsmRegClass::x87_reg | X86InlineAsmRegClass::mmx_reg,
+        ) => unreachable!("clobber-only"),
+        InlineAsmRegClass::SpirV(SpirVInlineAsmRegClass::reg) => return None,
+        InlineAsmRegClass::S390x(S390xInlineAsmRegClass::reg) => "r",
+        InlineAsmRegClass::S390x(S390xInlineAsmRegClass::freg) => "f",
+        InlineAsmRegClass::Err => unreachable!(),
     };
 
-    ConstraintOrRegister::Constraint(constraint)
+    Some(constraint)
 }
 
 /// Type to use for outputs that are discarded. It doesn't really matter what
@@ -602,11 +774,9 @@ fn reg_to_gcc(reg: InlineAsmRegOrRegClass) -> ConstraintOrRegister {
 fn dummy_output_type<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, reg: InlineAsmRegClass) -> Type<'gcc> {
     match reg {
         InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::reg) => cx.type_i32(),
-        InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::preg) => unimplemented!(),
+        InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::preg) => unreachable!("clobber-only"),
         InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg)
-        | InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg_low16) => {
-            unimplemented!()
-        }
+        | InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg_low16) => cx.type_f32(),
         InlineAsmRegClass::Arm(ArmInlineAsmRegClass::reg)=> cx.type_i32(),
         InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg)
         | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg_low16) => cx.type_f32(),
@@ -615,15 +785,18 @@ fn dummy_output_type<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, reg: InlineAsmRegCl
         | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg_low8) => cx.type_f64(),
         InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg)
         | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low8)
-        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low4) => {
-            unimplemented!()
-        }
-        InlineAsmRegClass::Avr(_) => unimplemented!(),
-        InlineAsmRegClass::Bpf(_) => unimplemented!(),
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low4) => cx.type_vector(cx.type_i64(), 2),
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg)
+        | InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_upper) => cx.type_i8(),
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_pair)
+        | InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_iw)
+        | InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_ptr) => cx.type_i16(),
+        InlineAsmRegClass::Bpf(BpfInlineAsmRegClass::reg) => cx.type_i64(),
+        InlineAsmRegClass::Bpf(BpfInlineAsmRegClass::wreg) => cx.type_i32(),
         InlineAsmRegClass::Hexagon(HexagonInlineAsmRegClass::reg) => cx.type_i32(),
         InlineAsmRegClass::Mips(MipsInlineAsmRegClass::reg) => cx.type_i32(),
         InlineAsmRegClass::Mips(MipsInlineAsmRegClass::freg) => cx.type_f32(),
-        InlineAsmRegClass::Msp430(_) => unimplemented!(),
+        InlineAsmRegClass::Msp430(Msp430InlineAsmRegClass::reg) => cx.type_i16(),
         InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg16) => cx.type_i16(),
         InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg32) => cx.type_i32(),
         InlineAsmRegClass::Nvptx(NvptxInlineAsmRegClass::reg64) => cx.type_i64(),
@@ -636,19 +809,20 @@ fn dummy_output_type<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, reg: InlineAsmRegCl
         },
         InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::reg) => cx.type_i32(),
         InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::freg) => cx.type_f32(),
-        InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::vreg) => cx.type_f32(),
+        InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::vreg) => unreachable!("unsupported register class"),
         InlineAsmRegClass::X86(X86InlineAsmRegClass::reg)
         | InlineAsmRegClass::X86(X86InlineAsmRegClass::reg_abcd) => cx.type_i32(),
         InlineAsmRegClass::X86(X86InlineAsmRegClass::reg_byte) => cx.type_i8(),
-        InlineAsmRegClass::X86(X86InlineAsmRegClass::mmx_reg) => unimplemented!(),
         InlineAsmRegClass::X86(X86InlineAsmRegClass::xmm_reg)
         | InlineAsmRegClass::X86(X86InlineAsmRegClass::ymm_reg)
         | InlineAsmRegClass::X86(X86InlineAsmRegClass::zmm_reg) => cx.type_f32(),
-        InlineAsmRegClass::X86(X86InlineAsmRegClass::x87_reg) => unimplemented!(),
+        InlineAsmRegClass::X86(
+            X86InlineAsmRegClass::x87_reg | X86InlineAsmRegClass::mmx_reg,
+        ) => unreachable!("clobber-only"),
         InlineAsmRegClass::X86(X86InlineAsmRegClass::kreg) => cx.type_i16(),
         InlineAsmRegClass::Wasm(WasmInlineAsmRegClass::local) => cx.type_i32(),
         InlineAsmRegClass::SpirV(SpirVInlineAsmRegClass::reg) => {
-            bug!("LLVM backend does not support SPIR-V")
+            bug!("GCC backend does not support SPIR-V")
         },
         InlineAsmRegClass::S390x(S390xInlineAsmRegClass::reg) => cx.type_i32(),
         InlineAsmRegClass::S390x(S390xInlineAsmRegClass::freg) => cx.type_f64(),
@@ -659,10 +833,8 @@ fn dummy_output_type<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, reg: InlineAsmRegCl
 impl<'gcc, 'tcx> AsmMethods for CodegenCx<'gcc, 'tcx> {
     fn codegen_global_asm(&self, template: &[InlineAsmTemplatePiece], operands: &[GlobalAsmOperandRef], options: InlineAsmOptions, _line_spans: &[Span]) {
         let asm_arch = self.tcx.sess.asm_arch.unwrap();
-
-        // Default to Intel syntax on x86
-        let intel_syntax = matches!(asm_arch, InlineAsmArch::X86 | InlineAsmArch::X86_64)
-            && !options.contains(InlineAsmOptions::ATT_SYNTAX);
+        let is_x86 = matches!(asm_arch, InlineAsmArch::X86 | InlineAsmArch::X86_64);
+        let att_dialect = is_x86 && options.contains(InlineAsmOptions::ATT_SYNTAX);
 
         // Build the template string
         let mut template_str = String::new();
@@ -695,12 +867,19 @@ impl<'gcc, 'tcx> AsmMethods for CodegenCx<'gcc, 'tcx> {
             }
         }
 
+        // NOTE: on x86, GCC emits its own asm in the Intel syntax (-masm=intel), which is also the
+        // default of global_asm!, so only AT&T asm needs to switch the dialect. The template can
+        // switch the dialect itself, so the Intel syntax is always restored at the end.
+        // The assembler of the other architectures has a single dialect.
         let template_str =
-            if intel_syntax {
+            if att_dialect {
+                format!(".att_syntax\n\t{}\n\t.intel_syntax noprefix", template_str)
+            }
+            else if is_x86 {
                 format!("{}\n\t.intel_syntax noprefix", template_str)
             }
             else {
-                format!(".att_syntax\n\t{}\n\t.intel_syntax noprefix", template_str)
+                template_str
             };
         // NOTE: seems like gcc will put the asm in the wrong section, so set it to .text manually.
         let template_str = format!(".pushsection .text\n{}\n.popsection", template_str);
@@ -708,35 +887,183 @@ impl<'gcc, 'tcx> AsmMethods for CodegenCx<'gcc, 'tcx> {
     }
 }
 
+impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
+    /// Emits the body of a `#[naked]` function as global asm.
+    ///
+    /// TODO(antoyo): use the naked attribute on targets that support it when libgccjit supports
+    /// it: it is not one of the function attributes that libgccjit can set.
+    pub fn codegen_naked_fn(&self, instance: Instance<'tcx>, linkage: Linkage) {
+        let tcx = self.tcx;
+        let mir = tcx.instance_mir(instance.def);
+        let asm = mir.basic_blocks().iter()
+            .find_map(|block| {
+                match block.terminator().kind {
+                    TerminatorKind::InlineAsm { template, ref operands, options, line_spans, .. } =>
+                        Some((template, operands, options, line_spans)),
+                    _ => None,
+                }
+            });
+        let (template, operands, options, line_spans) =
+            match asm {
+                Some(asm) => asm,
+                None => {
+                    tcx.sess.span_err(mir.span, "naked functions must contain a single asm block");
+                    return;
+                },
+            };
+
+        // Naked functions can only have const and sym operands, which are all resolved to strings.
+        let operands: Vec<_> = operands.iter()
+            .map(|operand| {
+                let string =
+                    match *operand {
+                        InlineAsmOperand::Const { ref value } => {
+                            let const_value = self.eval_naked_fn_constant(instance, value);
+                            let ty = instance.subst_mir_and_normalize_erasing_regions(tcx, ty::ParamEnv::reveal_all(), value.ty());
+                            common::asm_const_to_str(tcx, value.span, const_value, self.layout_of(ty))
+                        },
+                        InlineAsmOperand::SymFn { ref value } => {
+                            let literal = instance.subst_mir_and_normalize_erasing_regions(tcx, ty::ParamEnv::reveal_all(), value.literal);
+                            match *literal.ty().kind() {
+                                ty::FnDef(def_id, substs) => {
+                                    let instance = Instance::resolve_for_fn_ptr(tcx, ty::ParamEnv::reveal_all(), def_id, substs)
+                                        .expect("resolve sym fn");
+                                    mangle_name(tcx.symbol_name(instance).name)
+                                },
+                                _ => span_bug!(value.span, "invalid type for asm sym (fn)"),
+                            }
+                        },
+                        InlineAsmOperand::SymStatic { def_id } => {
+                            mangle_name(tcx.symbol_name(Instance::mono(tcx, def_id)).name)
+                        },
+                        InlineAsmOperand::In { .. }
+                        | InlineAsmOperand::Out { .. }
+                        | InlineAsmOperand::InOut { .. } => {
+                            span_bug!(mir.span, "invalid operand in naked function")
+                        },
+                    };
+                GlobalAsmOperandRef::Const { string }
+            })
+            .collect();
+
+        // NOTE: the function is declared with this name in declare_raw_fn().
+        let name = mangle_name(tcx.symbol_name(instance).name);
+        let is_elf = !self.sess().target.is_like_osx && !self.sess().target.is_like_windows;
+        let attrs = tcx.codegen_fn_attrs(instance.def_id());
+
+        let section =
+            match attrs.link_section {
+                Some(section) => Some(section.to_string()),
+                None if self.sess().opts.debugging_opts.function_sections.unwrap_or(self.sess().target.function_sections) =>
+                    Some(format!(".text.{}", name)),
+                None => None,
+            };
+
+        let mut prologue = String::new();
+        if let Some(ref section) = section {
+            if is_elf {
+                // NOTE: % is used instead of @ since @ starts a comment on ARM.
+                prologue.push_str(&format!(".pushsection {},\"ax\",%progbits\n", section));
+            }
+            else {
+                prologue.push_str(&format!(".pushsection {}\n", section));
+            }
+        }
+        if let Some(align) = attrs.alignment {
+            prologue.push_str(&format!(".balign {}\n", align));
+        }
+        if !matches!(linkage, Linkage::Internal | Linkage::Private) {
+            prologue.push_str(&format!(".globl {}\n", name));
+        }
+        if is_elf {
+            prologue.push_str(&format!(".type {}, %function\n", name));
+        }
+        prologue.push_str(&format!("{}:\n", name));
+
+        let mut epilogue = String::from("\n");
+        if is_elf {
+            epilogue.push_str(&format!(".size {}, .-{}\n", name, name));
+        }
+        if section.is_some() {
+            epilogue.push_str(".popsection\n");
+        }
+
+        let mut pieces = Vec::with_capacity(template.len() + 2);
+        pieces.push(InlineAsmTemplatePiece::String(prologue));
+        pieces.extend(template.iter().cloned());
+        pieces.push(InlineAsmTemplatePiece::String(epilogue));
+
+        self.codegen_global_asm(&pieces, &operands, options, line_spans);
+    }
+
+    fn eval_naked_fn_constant(&self, instance: Instance<'tcx>, constant: &mir::Constant<'tcx>) -> ConstValue<'tcx> {
+        let tcx = self.tcx;
+        let literal = instance.subst_mir_and_normalize_erasing_regions(tcx, ty::ParamEnv::reveal_all(), constant.literal);
+        let ct =
+            match literal {
+                mir::ConstantKind::Ty(ct) => ct,
+                mir::ConstantKind::Val(value, _) => return value,
+            };
+        match ct.val() {
+            ty::ConstKind::Unevaluated(ct) => {
+                tcx.const_eval_resolve(ty::ParamEnv::reveal_all(), ct, None)
+                    .unwrap_or_else(|_| span_bug!(constant.span, "erroneous constant not captured by required_consts"))
+            },
+            ty::ConstKind::Value(value) => value,
+            err => span_bug!(constant.span, "encountered bad ConstKind after monomorphizing: {:?}", err),
+        }
+    }
+}
+
 fn modifier_to_gcc(arch: InlineAsmArch, reg: InlineAsmRegClass, modifier: Option<char>) -> Option<char> {
     match reg {
+        // NOTE: GCC uses the same modifiers as rustc for the general registers (w and x) and
+        // prints the full x register by default, like rustc.
         InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::reg) => modifier,
-        InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::preg) => modifier,
+        InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::preg) => unreachable!("clobber-only"),
         InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg)
-        | InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg_low16) => {
-            unimplemented!()
-        }
-        InlineAsmRegClass::Arm(ArmInlineAsmRegClass::reg)  => unimplemented!(),
+        | InlineAsmRegClass::AArch64(AArch64InlineAsmRegClass::vreg_low16) => match modifier {
+            // NOTE: GCC prints the v register by default and has no modifier for it.
+            None | Some('v') => None,
+            Some(modifier @ ('b' | 'h' | 's' | 'd' | 'q')) => Some(modifier),
+            _ => unreachable!(),
+        },
+        InlineAsmRegClass::Arm(ArmInlineAsmRegClass::reg) => None,
         InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg)
-        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg_low16) => unimplemented!(),
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::sreg_low16) => None,
+        // NOTE: GCC needs the P modifier to print the name of a double-precision register.
         InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg)
         | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg_low16)
-        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg_low8) => unimplemented!(),
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::dreg_low8) => Some('P'),
+        // NOTE: the e and f modifiers select the low and high double-precision halves of a quad
+        // register, like in rustc.
         InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg)
         | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low8)
-        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low4) => {
-            unimplemented!()
-        }
-        InlineAsmRegClass::Avr(_) => unimplemented!(),
-        InlineAsmRegClass::Bpf(_) => unimplemented!(),
-        InlineAsmRegClass::Hexagon(_) => unimplemented!(),
-        InlineAsmRegClass::Mips(_) => unimplemented!(),
-        InlineAsmRegClass::Msp430(_) => unimplemented!(),
-        InlineAsmRegClass::Nvptx(_) => unimplemented!(),
-        InlineAsmRegClass::PowerPC(_) => unimplemented!(),
+        | InlineAsmRegClass::Arm(ArmInlineAsmRegClass::qreg_low4) => match modifier {
+            None => Some('q'),
+            Some(modifier @ ('e' | 'f')) => Some(modifier),
+            _ => unreachable!(),
+        },
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg)
+        | InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_upper) => None,
+        // NOTE: GCC prints the low and high registers of a pair with the A and B modifiers.
+        InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_pair)
+        | InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_iw)
+        | InlineAsmRegClass::Avr(AvrInlineAsmRegClass::reg_ptr) => match modifier {
+            None => None,
+            Some('l') => Some('A'),
+            Some('h') => Some('B'),
+            _ => unreachable!(),
+        },
+        InlineAsmRegClass::Bpf(_)
+        | InlineAsmRegClass::Mips(_)
+        | InlineAsmRegClass::Msp430(_)
+        | InlineAsmRegClass::PowerPC(_) => None,
+        InlineAsmRegClass::Hexagon(_) | InlineAsmRegClass::Nvptx(_) => unreachable!("unsupported register class"),
+        // NOTE: rustc has no modifiers for RISC-V.
         InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::reg)
-        | InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::freg) => unimplemented!(),
-        InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::vreg) => unimplemented!(),
+        | InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::freg) => None,
+        InlineAsmRegClass::RiscV(RiscVInlineAsmRegClass::vreg) => unreachable!("unsupported register class"),
         InlineAsmRegClass::X86(X86InlineAsmRegClass::reg)
         | InlineAsmRegClass::X86(X86InlineAsmRegClass::reg_abcd) => match modifier {
             None => if arch == InlineAsmArch::X86_64 { Some('q') } else { Some('k') },
@@ -763,12 +1090,12 @@ fn modifier_to_gcc(arch: InlineAsmArch, reg: InlineAsmRegClass, modifier: Option
         InlineAsmRegClass::X86(X86InlineAsmRegClass::x87_reg | X86InlineAsmRegClass::mmx_reg) => {
             unreachable!("clobber-only")
         }
-        InlineAsmRegClass::Wasm(WasmInlineAsmRegClass::local) => unimplemented!(),
+        InlineAsmRegClass::Wasm(WasmInlineAsmRegClass::local) => unreachable!("unsupported register class"),
         InlineAsmRegClass::SpirV(SpirVInlineAsmRegClass::reg) => {
-            bug!("LLVM backend does not support SPIR-V")
+            bug!("GCC backend does not support SPIR-V")
         },
-        InlineAsmRegClass::S390x(S390xInlineAsmRegClass::reg) => unimplemented!(),
-        InlineAsmRegClass::S390x(S390xInlineAsmRegClass::freg) => unimplemented!(),
+        InlineAsmRegClass::S390x(S390xInlineAsmRegClass::reg)
+        | InlineAsmRegClass::S390x(S390xInlineAsmRegClass::freg) => None,
         InlineAsmRegClass::Err => unreachable!(),
     }
 }
@@ -6,15 +6,20 @@ use gccjit::{
     FunctionType,
     GlobalKind,
 };
+use rustc_ast::ast::InlineAsmOptions;
 use rustc_middle::dep_graph;
 use rustc_middle::ty::TyCtxt;
-use rustc_middle::mir::mono::Linkage;
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::mir::TerminatorKind;
+use rustc_middle::mir::mono::{CodegenUnit, Linkage, MonoItem};
 use rustc_codegen_ssa::{ModuleCodegen, ModuleKind};
 use rustc_codegen_ssa::base::maybe_create_entry_wrapper;
 use rustc_codegen_ssa::mono_item::MonoItemExt;
 use rustc_codegen_ssa::traits::DebugInfoMethods;
-use rustc_session::config::DebugInfo;
+use rustc_session::Session;
+use rustc_session::config::{BranchProtection, CFProtection, DebugInfo, PAuthKey, PacRet};
 use rustc_span::Symbol;
+use rustc_target::spec::{FramePointer, StackProbeType, StackProtector};
 
 use crate::GccContext;
 use crate::builder::Builder;
@@ -52,6 +57,163 @@ pub fn linkage_to_gcc(linkage: Linkage) -> FunctionType {
     }
 }
 
+/// Add the GCC flags selecting the target and the assembly syntax used by the codegen.
+///
+/// NOTE: they must also be set on the contexts used to check what libgccjit supports, so that the
+/// checks are done for the same target.
+pub fn add_target_options(context: &Context<'_>, sess: &Session) {
+    let arch = &*sess.target.arch;
+    if arch == "x86" || arch == "x86_64" {
+        context.add_command_line_option("-masm=intel");
+        // TODO(antoyo): only add the following cli argument if the feature is supported.
+        context.add_command_line_option("-msse2");
+        context.add_command_line_option("-mavx2");
+        context.add_command_line_option("-msha");
+        context.add_command_line_option("-mpclmul");
+    }
+    // NOTE: a multilib x86-64 libgccjit can generate code for i686 when asked to.
+    if arch == "x86" {
+        context.add_command_line_option("-m32");
+    }
+}
+
+/// Translate the session's stack and control-flow hardening options to the equivalent GCC flags.
+pub fn add_hardening_options(context: &Context<'_>, sess: &Session) {
+    match sess.stack_protector() {
+        StackProtector::None => context.add_command_line_option("-fno-stack-protector"),
+        StackProtector::Basic => context.add_command_line_option("-fstack-protector"),
+        StackProtector::Strong => context.add_command_line_option("-fstack-protector-strong"),
+        StackProtector::All => context.add_command_line_option("-fstack-protector-all"),
+    }
+
+    match sess.target.stack_probes {
+        StackProbeType::None => context.add_command_line_option("-fno-stack-clash-protection"),
+        StackProbeType::Inline => context.add_command_line_option("-fstack-clash-protection"),
+        // NOTE: Call asks for a call to __rust_probestack in the prologue of the functions with a
+        // large frame, which GCC cannot emit. The inline probes of GCC are acceptable instead: like
+        // __rust_probestack, they touch every page of the new frame before it is used, so a stack
+        // overflow still hits the guard page. __rust_probestack is then just not called.
+        // InlineOrCall only selects between the two depending on the LLVM version, which does not
+        // apply to GCC.
+        StackProbeType::Call | StackProbeType::InlineOrCall { .. } =>
+            context.add_command_line_option("-fstack-clash-protection"),
+    }
+
+    // NOTE: when those options are enabled, GCC also emits the .note.gnu.property section that the
+    // linker needs to keep CET/BTI enabled for the whole binary.
+    let arch = &*sess.target.arch;
+    if arch == "x86" || arch == "x86_64" {
+        let cf_protection =
+            match sess.opts.debugging_opts.cf_protection {
+                CFProtection::None => None,
+                CFProtection::Branch => Some("branch"),
+                CFProtection::Return => Some("return"),
+                CFProtection::Full => Some("full"),
+            };
+        if let Some(cf_protection) = cf_protection {
+            context.add_command_line_option(&format!("-fcf-protection={}", cf_protection));
+        }
+    }
+
+    if arch == "aarch64" {
+        if let Some(protection) = branch_protection(sess) {
+            context.add_command_line_option(&format!("-mbranch-protection={}", protection));
+        }
+    }
+}
+
+/// The value of GCC's AArch64 branch-protection option that matches -Zbranch-protection.
+fn branch_protection(sess: &Session) -> Option<String> {
+    let BranchProtection { bti, pac_ret } = sess.opts.debugging_opts.branch_protection?;
+    let mut protections = vec![];
+    if bti {
+        protections.push("bti");
+    }
+    if let Some(PacRet { leaf, key }) = pac_ret {
+        protections.push("pac-ret");
+        if leaf {
+            protections.push("leaf");
+        }
+        if key == PAuthKey::B {
+            protections.push("b-key");
+        }
+    }
+    let protection =
+        if protections.is_empty() {
+            "none".to_string()
+        }
+        else {
+            protections.join("+")
+        };
+    Some(protection)
+}
+
+fn frame_pointer(sess: &Session) -> FramePointer {
+    // "mcount" function relies on stack pointer.
+    // See <https://sourceware.org/binutils/docs/gprof/Implementation.html>.
+    if sess.instrument_mcount() || sess.opts.cg.force_frame_pointers == Some(true) {
+        FramePointer::Always
+    }
+    else {
+        sess.target.frame_pointer
+    }
+}
+
+/// Translate the frame pointer, unwind table, red zone and PLT policies to the equivalent GCC
+/// flags.
+///
+/// NOTE: the gccjit version in use cannot set those options per function, so the policies are set
+/// for the whole module. Since they are the same for every function of a session, this is
+/// equivalent.
+pub fn add_frame_options(context: &Context<'_>, sess: &Session) {
+    let arch = &*sess.target.arch;
+
+    match frame_pointer(sess) {
+        FramePointer::Always => context.add_command_line_option("-fno-omit-frame-pointer"),
+        FramePointer::NonLeaf => {
+            context.add_command_line_option("-fno-omit-frame-pointer");
+            if arch == "x86" || arch == "x86_64" || arch == "aarch64" {
+                context.add_command_line_option("-momit-leaf-frame-pointer");
+            }
+        },
+        FramePointer::MayOmit => (),
+    }
+
+    if sess.must_emit_unwind_tables() {
+        context.add_command_line_option("-funwind-tables");
+        context.add_command_line_option("-fasynchronous-unwind-tables");
+    }
+    else if sess.opts.cg.force_unwind_tables == Some(false) {
+        context.add_command_line_option("-fno-unwind-tables");
+        context.add_command_line_option("-fno-asynchronous-unwind-tables");
+    }
+
+    if arch == "x86_64" && sess.opts.debugging_opts.no_redzone.unwrap_or(sess.target.disable_redzone) {
+        context.add_command_line_option("-mno-red-zone");
+    }
+
+    if !sess.needs_plt() {
+        context.add_command_line_option("-fno-plt");
+    }
+}
+
+/// Whether a function of the codegen unit contains an inline asm that may use the stack.
+fn has_asm_using_stack(tcx: TyCtxt<'_>, cgu: &CodegenUnit<'_>) -> bool {
+    cgu.items().keys().any(|item| {
+        match *item {
+            MonoItem::Fn(instance) =>
+                tcx.instance_mir(instance.def).basic_blocks().iter()
+                    .any(|block| {
+                        match block.terminator().kind {
+                            TerminatorKind::InlineAsm { options, .. } => !options.contains(InlineAsmOptions::NOSTACK),
+                            _ => false,
+                        }
+                    }),
+            _ => false,
+        }
+    })
+}
+
 pub fn compile_codegen_unit<'tcx>(tcx: TyCtxt<'tcx>, cgu_name: Symbol, supports_128bit_integers: bool) -> (ModuleCodegen<GccContext>, u64) {
     let prof_timer = tcx.prof.generic_activity("codegen_module");
     let start_time = Instant::now();
@@ -76,13 +238,14 @@ pub fn compile_codegen_unit<'tcx>(tcx: TyCtxt<'tcx>, cgu_name: Symbol, supports_
         // Instantiate monomorphizations without filling out definitions yet...
         //let llvm_module = ModuleLlvm::new(tcx, &cgu_name.as_str());
         let context = Context::default();
-        // TODO(antoyo): only set on x86 platforms.
-        context.add_command_line_option("-masm=intel");
-        // TODO(antoyo): only add the following cli argument if the feature is supported.
-        context.add_command_line_option("-msse2");
-        context.add_command_line_option("-mavx2");
-        context.add_command_line_option("-msha");
-        context.add_command_line_option("-mpclmul");
+        add_target_options(&context, tcx.sess);
+        let arch = &*tcx.sess.target.arch;
+        // NOTE: needed so that the 16-byte compare-and-swap is inlined as cmpxchg16b. This is added
+        // to every codegen unit, whatever its functions enable: GCC only uses this instruction for
+        // the 16-byte atomics, which rustc only emits where cmpxchg16b is available.
+        if arch == "x86_64" {
+            context.add_command_line_option("-mcx16");
+        }
         // FIXME(antoyo): the following causes an illegal instruction on vmovdqu64 in std_example on my CPU.
         // Only add if the CPU supports it.
         //context.add_command_line_option("-mavx512f");
@@ -99,6 +262,16 @@ pub fn compile_codegen_unit<'tcx>(tcx: TyCtxt<'tcx>, cgu_name: Symbol, supports_
             context.add_command_line_option("-fdata-sections");
         }
 
+        add_hardening_options(&context, tcx.sess);
+        add_frame_options(&context, tcx.sess);
+        // NOTE: an inline asm without nostack may push to the stack, which would overwrite the red
+        // zone. LLVM does not use the red zone in the functions containing such an asm, but the
+        // gccjit version in use cannot set this option per function, so it is set for the whole
+        // codegen unit.
+        if arch == "x86_64" && has_asm_using_stack(tcx, cgu) {
+            context.add_command_line_option("-mno-red-zone");
+        }
+
         if env::var("CG_GCCJIT_DUMP_CODE").as_deref() == Ok("1") {
             context.set_dump_code_on_compile(true);
         }
@@ -125,7 +298,13 @@ pub fn compile_codegen_unit<'tcx>(tcx: TyCtxt<'tcx>, cgu_name: Symbol, supports_
             }
 
             // ... and now that we have everything pre-defined, fill out those definitions.
-            for &(mono_item, _) in &mono_items {
+            for &(mono_item, (linkage, _)) in &mono_items {
+                if let MonoItem::Fn(instance) = mono_item {
+                    if tcx.codegen_fn_attrs(instance.def_id()).flags.contains(CodegenFnAttrFlags::NAKED) {
+                        cx.codegen_naked_fn(instance, linkage);
+                        continue;
+                    }
+                }
                 mono_item.define::<Builder<'_, '_, '_>>(&cx);
             }
 
@@ -47,6 +47,7 @@ use rustc_target::spec::{HasTargetSpec, Target};
 
 use crate::common::{SignType, TypeReflection, type_is_pointer};
 use crate::context::CodegenCx;
+use crate::intrinsic::llvm;
 use crate::type_of::LayoutGccExt;
 
 // TODO(antoyo)
@@ -93,22 +94,16 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
         }
     }
 
-    fn atomic_extremum(&mut self, operation: ExtremumOperation, dst: RValue<'gcc>, src: RValue<'gcc>, order: AtomicOrdering) -> RValue<'gcc> {
-        let size = src.get_type().get_size();
+    fn atomic_extremum(&mut self, operation: ExtremumOperation, dst: RValue<'gcc>, src: RValue<'gcc>, order: AtomicOrdering, unsigned: bool) -> RValue<'gcc> {
+        // NOTE: GCC does not have __atomic_fetch_max and __atomic_fetch_min builtins, so this is
+        // done with a compare-and-swap loop.
+        let src_type = src.get_type();
+        let size = self.atomic_size(src_type);
 
         let func = self.current_func();
 
-        let load_ordering =
-            match order {
-                // TODO(antoyo): does this make sense?
-                AtomicOrdering::AcquireRelease | AtomicOrdering::Release => AtomicOrdering::Acquire,
-                _ => order.clone(),
-            };
-        let previous_value = self.atomic_load(dst.get_type(), dst, load_ordering.clone(), Size::from_bytes(size));
-        let previous_var = func.new_local(None, previous_value.get_type(), "previous_value");
-        let return_value = func.new_local(None, previous_value.get_type(), "return_value");
-        self.llbb().add_assignment(None, previous_var, previous_value);
-        self.llbb().add_assignment(None, return_value, previous_var.to_rvalue());
+        let load_ordering = to_load_ordering(order.clone());
+        let previous_var = func.new_local(None, src_type, "previous_value");
 
         let while_block = func.new_block("while");
         let after_block = func.new_block("after_while");
@@ -118,13 +113,26 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
         // state need to be updated.
         self.switch_to_block(while_block);
 
+        let previous_value = self.atomic_load(src_type, dst, load_ordering.clone(), Size::from_bytes(size));
+        self.llbb().add_assignment(None, previous_var, previous_value);
+
         let comparison_operator =
             match operation {
                 ExtremumOperation::Max => ComparisonOp::LessThan,
                 ExtremumOperation::Min => ComparisonOp::GreaterThan,
             };
 
-        let cond1 = self.context.new_comparison(None, comparison_operator, previous_var.to_rvalue(), self.context.new_cast(None, src, previous_value.get_type()));
+        // NOTE: the operands might not have the signedness of the operation, so cast them before
+        // comparing.
+        let compared_type =
+            if unsigned {
+                src_type.to_unsigned(self.cx)
+            }
+            else {
+                src_type.to_signed(self.cx)
+            };
+        let previous = self.context.new_cast(None, previous_var.to_rvalue(), compared_type);
+        let cond1 = self.context.new_comparison(None, comparison_operator, previous, self.context.new_cast(None, src, compared_type));
         let compare_exchange = self.compare_exchange(dst, previous_var, src, order, load_ordering, false);
         let cond2 = self.cx.context.new_unary_op(None, UnaryOp::LogicalNegate, compare_exchange.get_type(), compare_exchange);
         let cond = self.cx.context.new_binary_op(None, BinaryOp::LogicalAnd, self.cx.bool_type, cond1, cond2);
@@ -135,14 +143,19 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
         // state need to be updated.
         self.switch_to_block(after_block);
 
-        return_value.to_rvalue()
+        previous_var.to_rvalue()
     }
 
+    /// Returns whether `src` was stored in `dst`.
+    /// When it was not, `cmp` is updated with the current value, except for 16-byte values.
     fn compare_exchange(&self, dst: RValue<'gcc>, cmp: LValue<'gcc>, src: RValue<'gcc>, order: AtomicOrdering, failure_order: AtomicOrdering, weak: bool) -> RValue<'gcc> {
-        let size = src.get_type().get_size();
+        let size = self.atomic_size(src.get_type());
+        if size == 16 {
+            return self.compare_and_swap16(dst, cmp.to_rvalue(), src, "__sync_bool_compare_and_swap_16");
+        }
         let compare_exchange = self.context.get_builtin_function(&format!("__atomic_compare_exchange_{}", size));
         let order = self.context.new_rvalue_from_int(self.i32_type, order.to_gcc());
-        let failure_order = self.context.new_rvalue_from_int(self.i32_type, failure_order.to_gcc());
+        let failure_order = self.context.new_rvalue_from_int(self.i32_type, to_load_ordering(failure_order).to_gcc());
         let weak = self.context.new_rvalue_from_int(self.bool_type, weak as i32);
 
         let void_ptr_type = self.context.new_type::<*mut ()>();
@@ -156,11 +169,77 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
         self.context.new_call(None, compare_exchange, &[dst, expected, src, weak, order, failure_order])
     }
 
+    /// Returns the size of the integer on which an atomic operation on a value of this type is
+    /// done.
+    fn atomic_size(&self, typ: Type<'gcc>) -> u64 {
+        if type_is_pointer(typ) {
+            self.tcx.data_layout.pointer_size.bytes()
+        }
+        else if self.is_non_native_int_type(typ) {
+            self.sess().fatal("128-bit atomics require a libgccjit with support for 128-bit integers")
+        }
+        else {
+            typ.get_size() as u64
+        }
+    }
+
+    /// Calls one of the 16-byte `__sync` compare-and-swap builtins.
+    // NOTE: GCC lowers the 16-byte __atomic builtins to calls into libatomic, while the __sync
+    // ones are lowered to cmpxchg16b with -mcx16. They are sequentially consistent, which is at
+    // least as strong as any ordering requested.
+    fn compare_and_swap16(&self, dst: RValue<'gcc>, expected: RValue<'gcc>, src: RValue<'gcc>, builtin_name: &str) -> RValue<'gcc> {
+        let compare_and_swap = self.context.get_builtin_function(builtin_name);
+        let volatile_void_ptr_type = self.context.new_type::<*mut ()>().make_volatile();
+        let dst = self.context.new_cast(None, dst, volatile_void_ptr_type);
+        let int_type = compare_and_swap.get_param(1).to_rvalue().get_type();
+        let expected = self.context.new_cast(None, expected, int_type);
+        let src = self.context.new_cast(None, src, int_type);
+        self.context.new_call(None, compare_and_swap, &[dst, expected, src])
+    }
+
+    /// Replaces the 16-byte value at `dst` by `new_value(previous)` with a compare-and-swap loop
+    /// and returns the previous value.
+    fn atomic_cas_loop16(&mut self, dst: RValue<'gcc>, typ: Type<'gcc>, new_value: impl Fn(&Self, RValue<'gcc>) -> RValue<'gcc>) -> RValue<'gcc> {
+        let func = self.current_func();
+        let previous_var = func.new_local(None, typ, "previous_value");
+        let loop_block = func.new_block("atomic_loop");
+        let after_block = func.new_block("atomic_loop_after");
+        self.llbb().end_with_jump(None, loop_block);
+
+        self.switch_to_block(loop_block);
+        let previous_value = self.atomic_load(typ, dst, AtomicOrdering::Monotonic, Size::from_bytes(16));
+        self.llbb().add_assignment(None, previous_var, previous_value);
+        let value = new_value(self, previous_var.to_rvalue());
+        let swapped = self.compare_and_swap16(dst, previous_var.to_rvalue(), value, "__sync_bool_compare_and_swap_16");
+        let not_swapped = self.context.new_unary_op(None, UnaryOp::LogicalNegate, swapped.get_type(), swapped);
+        loop_block.end_with_conditional(None, not_swapped, loop_block, after_block);
+
+        self.switch_to_block(after_block);
+        previous_var.to_rvalue()
+    }
+
+    fn atomic_rmw_value(&self, op: &AtomicRmwBinOp, previous: RValue<'gcc>, src: RValue<'gcc>) -> RValue<'gcc> {
+        let typ = previous.get_type();
+        let src = self.context.new_cast(None, src, typ);
+        let binary_op = |operation: BinaryOp| self.context.new_binary_op(None, operation, typ, previous, src);
+        match *op {
+            AtomicRmwBinOp::AtomicXchg => src,
+            AtomicRmwBinOp::AtomicAdd => binary_op(BinaryOp::Plus),
+            AtomicRmwBinOp::AtomicSub => binary_op(BinaryOp::Minus),
+            AtomicRmwBinOp::AtomicAnd => binary_op(BinaryOp::BitwiseAnd),
+            AtomicRmwBinOp::AtomicNand => self.context.new_unary_op(None, UnaryOp::BitwiseNegate, typ, binary_op(BinaryOp::BitwiseAnd)),
+            AtomicRmwBinOp::AtomicOr => binary_op(BinaryOp::BitwiseOr),
+            AtomicRmwBinOp::AtomicXor => binary_op(BinaryOp::BitwiseXor),
+            AtomicRmwBinOp::AtomicMax | AtomicRmwBinOp::AtomicMin | AtomicRmwBinOp::AtomicUMax | AtomicRmwBinOp::AtomicUMin =>
+                unreachable!("atomic extremums are handled by atomic_extremum"),
+        }
+    }
+
     pub fn assign(&self, lvalue: LValue<'gcc>, value: RValue<'gcc>) {
         self.llbb().add_assignment(None, lvalue, value);
     }
 
-    fn check_call<'b>(&mut self, _typ: &str, func: Function<'gcc>, args: &'b [RValue<'gcc>]) -> Cow<'b, [RValue<'gcc>]> {
+    fn check_call<'b>(&mut self, _typ: &str, func: Function<'gcc>, args: &'b [RValue<'gcc>], is_builtin: bool) -> Cow<'b, [RValue<'gcc>]> {
         let mut all_args_match = true;
         let mut param_types = vec![];
         let param_count = func.get_param_count();
@@ -184,7 +263,14 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
             .map(|(_i, (expected_ty, &actual_val))| {
                 let actual_ty = actual_val.get_type();
                 if expected_ty != actual_ty {
-                    self.bitcast(actual_val, expected_ty)
+                    // NOTE: the LLVM intrinsics can take integers of another size than the GCC
+                    // builtins, and a cast keeps their immediate arguments constant.
+                    if is_builtin && !actual_ty.is_vector() && !expected_ty.is_vector() && actual_ty.is_integral() && expected_ty.is_integral() && actual_ty.get_size() != expected_ty.get_size() {
+                        self.context.new_cast(None, actual_val, expected_ty)
+                    }
+                    else {
+                        self.bitcast(actual_val, expected_ty)
+                    }
                 }
                 else {
                     actual_val
@@ -261,10 +347,158 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
         self.block.get_function()
     }
 
+    /// Returns the builtin doing a non-temporal store of a value of this type, if the target has
+    /// one.
+    fn nontemporal_store_builtin(&self, typ: Type<'gcc>) -> Option<Function<'gcc>> {
+        // NOTE: GCC does not have a generic non-temporal store builtin.
+        if &*self.sess().target.arch != "x86_64" || !self.is_native_int_type(typ) {
+            return None;
+        }
+        let builtin_name =
+            match typ.get_size() {
+                4 => "__builtin_ia32_movnti",
+                8 => "__builtin_ia32_movnti64",
+                _ => return None,
+            };
+        Some(self.context.get_target_builtin_function(builtin_name))
+    }
+
+    /// Copies bytes between volatile pointers one at a time.
+    /// When the memory might overlap, the copy is done backward if the destination is after the
+    /// source.
+    fn volatile_copy(&mut self, dst: RValue<'gcc>, src: RValue<'gcc>, size: RValue<'gcc>, may_overlap: bool) {
+        let context = self.context;
+        let read_src = |index: RValue<'gcc>| context.new_array_access(None, src, index).to_rvalue();
+        if !may_overlap {
+            self.volatile_loop(dst, size, false, read_src);
+            return;
+        }
+
+        let func = self.current_func();
+        let forward_block = func.new_block("volatile_copy_forward");
+        let backward_block = func.new_block("volatile_copy_backward");
+        let after_block = func.new_block("volatile_copy_after");
+        let dst_after_src = self.context.new_comparison(None, ComparisonOp::GreaterThan, dst, src);
+        self.llbb().end_with_conditional(None, dst_after_src, backward_block, forward_block);
+
+        self.switch_to_block(forward_block);
+        self.volatile_loop(dst, size, false, read_src);
+        self.llbb().end_with_jump(None, after_block);
+
+        self.switch_to_block(backward_block);
+        self.volatile_loop(dst, size, true, read_src);
+        self.llbb().end_with_jump(None, after_block);
+
+        self.switch_to_block(after_block);
+    }
+
+    /// Copies memory that does not overlap with a non-temporal store builtin, one chunk of the size
+    /// of its value at a time, and copies the remaining bytes with memcpy.
+    fn nontemporal_copy(&mut self, nontemporal_store: Function<'gcc>, dst: RValue<'gcc>, src: RValue<'gcc>, size: RValue<'gcc>) {
+        let size_type = size.get_type();
+        let ptr_type = nontemporal_store.get_param(0).to_rvalue().get_type();
+        let value_type = nontemporal_store.get_param(1).to_rvalue().get_type();
+        let chunk_size = self.context.new_rvalue_from_long(size_type, value_type.get_size() as i64);
+        let chunk_count = self.context.new_binary_op(None, BinaryOp::Divide, size_type, size, chunk_size);
+        let dst_chunks = self.context.new_cast(None, dst, ptr_type);
+        // NOTE: the source is not necessarily aligned on the chunk size.
+        let src_chunks = self.context.new_cast(None, src, value_type.get_aligned(1).make_const().make_pointer());
+
+        let func = self.current_func();
+        let index = func.new_local(None, size_type, "nontemporal_index");
+        let condition_block = func.new_block("nontemporal_loop_condition");
+        let body_block = func.new_block("nontemporal_loop_body");
+        let after_block = func.new_block("nontemporal_loop_after");
+
+        self.llbb().add_assignment(None, index, self.context.new_rvalue_zero(size_type));
+        self.llbb().end_with_jump(None, condition_block);
+
+        let condition = self.context.new_comparison(None, ComparisonOp::LessThan, index.to_rvalue(), chunk_count);
+        condition_block.end_with_conditional(None, condition, body_block, after_block);
+
+        let destination = self.context.new_array_access(None, dst_chunks, index.to_rvalue()).get_address(None);
+        let value = self.context.new_array_access(None, src_chunks, index.to_rvalue()).to_rvalue();
+        let value = self.context.new_cast(None, value, value_type);
+        body_block.add_eval(None, self.context.new_call(None, nontemporal_store, &[destination, value]));
+        body_block.add_assignment_op(None, index, BinaryOp::Plus, self.context.new_rvalue_one(size_type));
+        body_block.end_with_jump(None, condition_block);
+
+        self.switch_to_block(after_block);
+        let copied_size = self.context.new_binary_op(None, BinaryOp::Mult, size_type, chunk_count, chunk_size);
+        let remaining_size = self.context.new_binary_op(None, BinaryOp::Minus, size_type, size, copied_size);
+        let dst = self.pointercast(dst, self.type_u8().make_pointer());
+        let src = self.pointercast(src, self.type_u8().make_const().make_pointer());
+        let dst = self.context.new_array_access(None, dst, copied_size).get_address(None);
+        let src = self.context.new_array_access(None, src, copied_size).get_address(None);
+        let dst = self.pointercast(dst, self.type_i8p());
+        let src = self.pointercast(src, self.type_ptr_to(self.type_void()));
+        let memcpy = self.context.get_builtin_function("memcpy");
+        self.block.add_eval(None, self.context.new_call(None, memcpy, &[dst, src, remaining_size]));
+    }
+
+    /// Writes `value(index)` to each byte of a volatile pointer.
+    fn volatile_loop(&mut self, dst: RValue<'gcc>, size: RValue<'gcc>, backward: bool, value: impl Fn(RValue<'gcc>) -> RValue<'gcc>) {
+        let func = self.current_func();
+        let index = func.new_local(None, size.get_type(), "volatile_index");
+        let zero = self.context.new_rvalue_zero(size.get_type());
+        let one = self.context.new_rvalue_one(size.get_type());
+        let condition_block = func.new_block("volatile_loop_condition");
+        let body_block = func.new_block("volatile_loop_body");
+        let after_block = func.new_block("volatile_loop_after");
+
+        let start = if backward { size } else { zero };
+        self.llbb().add_assignment(None, index, start);
+        self.llbb().end_with_jump(None, condition_block);
+
+        let condition =
+            if backward {
+                self.context.new_comparison(None, ComparisonOp::NotEquals, index.to_rvalue(), zero)
+            }
+            else {
+                self.context.new_comparison(None, ComparisonOp::LessThan, index.to_rvalue(), size)
+            };
+        condition_block.end_with_conditional(None, condition, body_block, after_block);
+
+        if backward {
+            body_block.add_assignment_op(None, index, BinaryOp::Minus, one);
+        }
+        let destination = self.context.new_array_access(None, dst, index.to_rvalue());
+        body_block.add_assignment(None, destination, value(index.to_rvalue()));
+        if !backward {
+            body_block.add_assignment_op(None, index, BinaryOp::Plus, one);
+        }
+        body_block.end_with_jump(None, condition_block);
+
+        self.switch_to_block(after_block);
+    }
+
+    /// Tell GCC about the alignment of a pointer passed to a memory builtin, so that it can use
+    /// wider moves.
+    fn assume_aligned(&mut self, ptr: RValue<'gcc>, align: Align) -> RValue<'gcc> {
+        if align.bytes() <= 1 {
+            return ptr;
+        }
+        let const_void_ptr_type = self.type_void().make_const().make_pointer();
+        let ptr = self.pointercast(ptr, const_void_ptr_type);
+        let assume_aligned = self.context.get_builtin_function("__builtin_assume_aligned");
+        let align = self.context.new_rvalue_from_long(self.sizet_type, align.bytes() as i64);
+        self.context.new_call(None, assume_aligned, &[ptr, align])
+    }
+
     fn function_call(&mut self, func: RValue<'gcc>, args: &[RValue<'gcc>], _funclet: Option<&Funclet>) -> RValue<'gcc> {
         // TODO(antoyo): remove when the API supports a different type for functions.
         let func: Function<'gcc> = self.cx.rvalue_as_function(func);
-        let args = self.check_call("call", func, args);
+        // NOTE: the LLVM intrinsics are implemented by the GCC builtins, whose arguments sometimes
+        // need to be adjusted.
+        let builtin_name = self.cx.intrinsic_builtins.borrow().get(&func).cloned();
+        let args =
+            if let Some(ref builtin_name) = builtin_name {
+                llvm::adjust_intrinsic_arguments(self, func, args.into(), builtin_name)
+            }
+            else {
+                args.into()
+            };
+        let args = self.check_call("call", func, &args, builtin_name.is_some());
 
         // gccjit requires to use the result of functions, even when it's not used.
         // That's why we assign the result to a local or call add_eval().
@@ -275,7 +509,12 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
             unsafe { RETURN_VALUE_COUNT += 1 };
             let result = current_func.new_local(None, return_type, &format!("returnValue{}", unsafe { RETURN_VALUE_COUNT }));
             self.block.add_assignment(None, result, self.cx.context.new_call(None, func, &args));
-            result.to_rvalue()
+            if let Some(ref builtin_name) = builtin_name {
+                llvm::adjust_intrinsic_return_value(self, result.to_rvalue(), builtin_name, &args)
+            }
+            else {
+                result.to_rvalue()
+            }
         }
         else {
             self.block.add_eval(None, self.cx.context.new_call(None, func, &args));
@@ -430,6 +669,9 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn switch(&mut self, value: RValue<'gcc>, default_block: Block<'gcc>, cases: impl ExactSizeIterator<Item = (u128, Block<'gcc>)>) {
+        // TODO(antoyo): lower the weights of the cold cases to __builtin_expect_with_probability
+        // when rustc_codegen_ssa gives them: for now, neither switch() nor cond_br() receive any
+        // weight.
         let mut gcc_cases = vec![];
         let typ = self.val_ty(value);
         for (on_val, dest) in cases {
@@ -493,7 +735,7 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     fn exactudiv(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
         // TODO(antoyo): convert the arguments to unsigned?
         // TODO(antoyo): poison if not exact.
-        a / b
+        self.gcc_udiv(a, b)
     }
 
     fn sdiv(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
@@ -505,8 +747,8 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
         // FIXME(antoyo): rustc_codegen_ssa::mir::intrinsic uses different types for a and b but they
         // should be the same.
         let typ = a.get_type().to_signed(self);
-        let b = self.context.new_cast(None, b, typ);
-        a / b
+        let b = self.gcc_int_cast(b, typ);
+        self.gcc_sdiv(a, b)
     }
 
     fn fdiv(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
@@ -522,12 +764,12 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn frem(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
-        if a.get_type().is_compatible_with(self.cx.float_type) {
+        if a.get_type().is_f32(self.cx) {
             let fmodf = self.context.get_builtin_function("fmodf");
             // FIXME(antoyo): this seems to produce the wrong result.
             return self.context.new_call(None, fmodf, &[a, b]);
         }
-        assert_eq!(a.get_type().unqualified(), self.cx.double_type);
+        assert!(a.get_type().is_f64(self.cx));
 
         let fmod = self.context.get_builtin_function("fmod");
         return self.context.new_call(None, fmod, &[a, b]);
@@ -572,7 +814,7 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn unchecked_sadd(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
-        a + b
+        self.gcc_add(a, b)
     }
 
     fn unchecked_uadd(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
@@ -580,7 +822,7 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn unchecked_ssub(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
-        a - b
+        self.gcc_sub(a, b)
     }
 
     fn unchecked_usub(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
@@ -589,11 +831,11 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn unchecked_smul(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
-        a * b
+        self.gcc_mul(a, b)
     }
 
     fn unchecked_umul(&mut self, a: RValue<'gcc>, b: RValue<'gcc>) -> RValue<'gcc> {
-        a * b
+        self.gcc_mul(a, b)
     }
 
     fn fadd_fast(&mut self, _lhs: RValue<'gcc>, _rhs: RValue<'gcc>) -> RValue<'gcc> {
@@ -643,40 +885,49 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
         unimplemented!();
     }
 
-    fn load(&mut self, _ty: Type<'gcc>, ptr: RValue<'gcc>, _align: Align) -> RValue<'gcc> {
+    fn load(&mut self, _ty: Type<'gcc>, ptr: RValue<'gcc>, align: Align) -> RValue<'gcc> {
         // TODO(antoyo): use ty.
         let block = self.llbb();
         let function = block.get_function();
+        // NOTE: libgccjit does not support specifying the alignment on the dereference, so we cast
+        // to type so it gets the proper alignment.
+        let value_type = ptr.dereference(None).to_rvalue().get_type().unqualified();
+        let aligned_type = value_type.get_aligned(align.bytes());
+        let ptr = self.cx.context.new_bitcast(None, ptr, aligned_type.make_pointer());
         // NOTE: instead of returning the dereference here, we have to assign it to a variable in
         // the current basic block. Otherwise, it could be used in another basic block, causing a
         // dereference after a drop, for instance.
-        // TODO(antoyo): handle align of the load instruction.
         let deref = ptr.dereference(None).to_rvalue();
-        let value_type = deref.get_type();
         unsafe { RETURN_VALUE_COUNT += 1 };
-        let loaded_value = function.new_local(None, value_type, &format!("loadedValue{}", unsafe { RETURN_VALUE_COUNT }));
+        let loaded_value = function.new_local(None, aligned_type, &format!("loadedValue{}", unsafe { RETURN_VALUE_COUNT }));
         block.add_assignment(None, loaded_value, deref);
         loaded_value.to_rvalue()
     }
 
     fn volatile_load(&mut self, _ty: Type<'gcc>, ptr: RValue<'gcc>) -> RValue<'gcc> {
         // TODO(antoyo): use ty.
-        let ptr = self.context.new_cast(None, ptr, ptr.get_type().make_volatile());
+        let value_type = ptr.dereference(None).to_rvalue().get_type();
+        let ptr = self.context.new_cast(None, ptr, value_type.make_volatile().make_pointer());
         ptr.dereference(None).to_rvalue()
     }
 
-    fn atomic_load(&mut self, _ty: Type<'gcc>, ptr: RValue<'gcc>, order: AtomicOrdering, size: Size) -> RValue<'gcc> {
-        // TODO(antoyo): use ty.
-        // TODO(antoyo): handle alignment.
+    fn atomic_load(&mut self, ty: Type<'gcc>, ptr: RValue<'gcc>, order: AtomicOrdering, size: Size) -> RValue<'gcc> {
+        // NOTE: rustc only does atomic accesses on naturally aligned pointers, which is what the
+        // builtins expect.
+        // NOTE: the 16-byte load cannot be a compare-and-swap that leaves the value unchanged,
+        // since that writes to the location and faults on read-only memory. __atomic_load_16 is
+        // done in libatomic, which must be linked.
         let atomic_load = self.context.get_builtin_function(&format!("__atomic_load_{}", size.bytes()));
-        let ordering = self.context.new_rvalue_from_int(self.i32_type, order.to_gcc());
+        let ordering = self.context.new_rvalue_from_int(self.i32_type, to_load_ordering(order).to_gcc());
 
         let volatile_const_void_ptr_type = self.context.new_type::<()>()
             .make_const()
             .make_volatile()
             .make_pointer();
         let ptr = self.context.new_cast(None, ptr, volatile_const_void_ptr_type);
-        self.context.new_call(None, atomic_load, &[ptr, ordering])
+        let value = self.context.new_call(None, atomic_load, &[ptr, ordering]);
+        // NOTE: the builtins return an unsigned integer.
+        self.context.new_cast(None, value, ty)
     }
 
     fn load_operand(&mut self, place: PlaceRef<'tcx, RValue<'gcc>>) -> OperandRef<'tcx, RValue<'gcc>> {
@@ -790,23 +1041,44 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
         self.store_with_flags(val, ptr, align, MemFlags::empty())
     }
 
-    fn store_with_flags(&mut self, val: RValue<'gcc>, ptr: RValue<'gcc>, align: Align, _flags: MemFlags) -> RValue<'gcc> {
+    fn store_with_flags(&mut self, val: RValue<'gcc>, ptr: RValue<'gcc>, align: Align, flags: MemFlags) -> RValue<'gcc> {
         let ptr = self.check_store(val, ptr);
         let destination = ptr.dereference(None);
-        // NOTE: libgccjit does not support specifying the alignment on the assignment, so we cast
-        // to type so it gets the proper alignment.
         let destination_type = destination.to_rvalue().get_type().unqualified();
-        let aligned_type = destination_type.get_aligned(align.bytes()).make_pointer();
-        let aligned_destination = self.cx.context.new_bitcast(None, ptr, aligned_type);
-        let aligned_destination = aligned_destination.dereference(None);
-        self.llbb().add_assignment(None, aligned_destination, val);
-        // TODO(antoyo): handle align and flags.
+        let nontemporal_store =
+            if flags.contains(MemFlags::NONTEMPORAL) {
+                self.nontemporal_store_builtin(destination_type)
+            }
+            else {
+                None
+            };
+        if let Some(nontemporal_store) = nontemporal_store {
+            let ptr_type = nontemporal_store.get_param(0).to_rvalue().get_type();
+            let value_type = nontemporal_store.get_param(1).to_rvalue().get_type();
+            let ptr = self.context.new_cast(None, ptr, ptr_type);
+            let val = self.context.new_cast(None, val, value_type);
+            self.llbb().add_eval(None, self.context.new_call(None, nontemporal_store, &[ptr, val]));
+        }
+        else {
+            // NOTE: libgccjit does not support specifying the alignment on the assignment, so we cast
+            // to type so it gets the proper alignment.
+            let mut aligned_type = destination_type.get_aligned(align.bytes());
+            if flags.contains(MemFlags::VOLATILE) {
+                aligned_type = aligned_type.make_volatile();
+            }
+            let aligned_destination = self.cx.context.new_bitcast(None, ptr, aligned_type.make_pointer());
+            let aligned_destination = aligned_destination.dereference(None);
+            self.llbb().add_assignment(None, aligned_destination, val);
+        }
         // NOTE: dummy value here since it's never used. FIXME(antoyo): API should not return a value here?
         self.cx.context.new_rvalue_zero(self.type_i32())
     }
 
     fn atomic_store(&mut self, value: RValue<'gcc>, ptr: RValue<'gcc>, order: AtomicOrdering, size: Size) {
-        // TODO(antoyo): handle alignment.
+        if size.bytes() == 16 {
+            self.atomic_cas_loop16(ptr, value.get_type(), |_, _| value);
+            return;
+        }
         let atomic_store = self.context.get_builtin_function(&format!("__atomic_store_{}", size.bytes()));
         let ordering = self.context.new_rvalue_from_int(self.i32_type, order.to_gcc());
         let volatile_const_void_ptr_type = self.context.new_type::<()>()
@@ -878,12 +1150,13 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn sext(&mut self, value: RValue<'gcc>, dest_ty: Type<'gcc>) -> RValue<'gcc> {
-        // TODO(antoyo): check that it indeed sign extend the value.
         if dest_ty.dyncast_vector().is_some() {
             // TODO(antoyo): nothing to do as it is only for LLVM?
             return value;
         }
-        self.context.new_cast(None, value, dest_ty)
+        // NOTE: casting from a signed type is what sign extends the value.
+        let value = self.gcc_int_cast(value, value.get_type().to_signed(self));
+        self.gcc_int_cast(value, dest_ty)
     }
 
     fn fptoui(&mut self, value: RValue<'gcc>, dest_ty: Type<'gcc>) -> RValue<'gcc> {
@@ -957,42 +1230,64 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     /* Miscellaneous instructions */
-    fn memcpy(&mut self, dst: RValue<'gcc>, _dst_align: Align, src: RValue<'gcc>, _src_align: Align, size: RValue<'gcc>, flags: MemFlags) {
-        assert!(!flags.contains(MemFlags::NONTEMPORAL), "non-temporal memcpy not supported");
+    fn memcpy(&mut self, dst: RValue<'gcc>, dst_align: Align, src: RValue<'gcc>, src_align: Align, size: RValue<'gcc>, flags: MemFlags) {
         let size = self.intcast(size, self.type_size_t(), false);
-        let _is_volatile = flags.contains(MemFlags::VOLATILE);
+        if flags.contains(MemFlags::VOLATILE) {
+            // NOTE: the memory builtins cannot do volatile accesses.
+            let dst = self.pointercast(dst, self.type_u8().make_volatile().make_pointer());
+            let src = self.pointercast(src, self.type_u8().make_volatile().make_pointer());
+            self.volatile_copy(dst, src, size, false);
+            return;
+        }
+        if flags.contains(MemFlags::NONTEMPORAL) {
+            // NOTE: on the targets without a non-temporal store builtin, this is only a hint, so a
+            // normal memcpy is done.
+            if let Some(nontemporal_store) = self.nontemporal_store_builtin(self.type_u64()) {
+                self.nontemporal_copy(nontemporal_store, dst, src, size);
+                return;
+            }
+        }
+        let dst = self.assume_aligned(dst, dst_align);
+        let src = self.assume_aligned(src, src_align);
         let dst = self.pointercast(dst, self.type_i8p());
         let src = self.pointercast(src, self.type_ptr_to(self.type_void()));
         let memcpy = self.context.get_builtin_function("memcpy");
-        // TODO(antoyo): handle aligns and is_volatile.
         self.block.add_eval(None, self.context.new_call(None, memcpy, &[dst, src, size]));
     }
 
     fn memmove(&mut self, dst: RValue<'gcc>, dst_align: Align, src: RValue<'gcc>, src_align: Align, size: RValue<'gcc>, flags: MemFlags) {
-        if flags.contains(MemFlags::NONTEMPORAL) {
-            // HACK(nox): This is inefficient but there is no nontemporal memmove.
-            let val = self.load(src.get_type(), src, src_align);
-            let ptr = self.pointercast(dst, self.type_ptr_to(self.val_ty(val)));
-            self.store_with_flags(val, ptr, dst_align, flags);
+        // NOTE: a non-temporal store is only a hint and copying overlapping memory in chunks would
+        // need to pick the direction of the copy, so the NONTEMPORAL flag is ignored here.
+        let size = self.intcast(size, self.type_size_t(), false);
+        if flags.contains(MemFlags::VOLATILE) {
+            // NOTE: the memory builtins cannot do volatile accesses.
+            let dst = self.pointercast(dst, self.type_u8().make_volatile().make_pointer());
+            let src = self.pointercast(src, self.type_u8().make_volatile().make_pointer());
+            self.volatile_copy(dst, src, size, true);
             return;
         }
-        let size = self.intcast(size, self.type_size_t(), false);
-        let _is_volatile = flags.contains(MemFlags::VOLATILE);
+        let dst = self.assume_aligned(dst, dst_align);
+        let src = self.assume_aligned(src, src_align);
         let dst = self.pointercast(dst, self.type_i8p());
         let src = self.pointercast(src, self.type_ptr_to(self.type_void()));
 
         let memmove = self.context.get_builtin_function("memmove");
-        // TODO(antoyo): handle is_volatile.
         self.block.add_eval(None, self.context.new_call(None, memmove, &[dst, src, size]));
     }
 
-    fn memset(&mut self, ptr: RValue<'gcc>, fill_byte: RValue<'gcc>, size: RValue<'gcc>, _align: Align, flags: MemFlags) {
-        let _is_volatile = flags.contains(MemFlags::VOLATILE);
+    fn memset(&mut self, ptr: RValue<'gcc>, fill_byte: RValue<'gcc>, size: RValue<'gcc>, align: Align, flags: MemFlags) {
+        let size = self.intcast(size, self.type_size_t(), false);
+        if flags.contains(MemFlags::VOLATILE) {
+            // NOTE: the memory builtins cannot do volatile accesses.
+            let ptr = self.pointercast(ptr, self.type_u8().make_volatile().make_pointer());
+            let fill_byte = self.context.new_cast(None, fill_byte, self.type_u8());
+            self.volatile_loop(ptr, size, false, |_index| fill_byte);
+            return;
+        }
+        let ptr = self.assume_aligned(ptr, align);
         let ptr = self.pointercast(ptr, self.type_i8p());
         let memset = self.context.get_builtin_function("memset");
-        // TODO(antoyo): handle align and is_volatile.
         let fill_byte = self.context.new_cast(None, fill_byte, self.i32_type);
-        let size = self.intcast(size, self.type_size_t(), false);
         self.block.add_eval(None, self.context.new_call(None, memset, &[ptr, fill_byte, size]));
     }
 
@@ -1151,7 +1446,23 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     fn atomic_cmpxchg(&mut self, dst: RValue<'gcc>, cmp: RValue<'gcc>, src: RValue<'gcc>, order: AtomicOrdering, failure_order: AtomicOrdering, weak: bool) -> RValue<'gcc> {
         let expected = self.current_func().new_local(None, cmp.get_type(), "expected");
         self.llbb().add_assignment(None, expected, cmp);
-        let success = self.compare_exchange(dst, expected, src, order, failure_order, weak);
+        let success =
+            if self.atomic_size(src.get_type()) == 16 {
+                // NOTE: the 16-byte compare-and-swap returns the previous value instead of
+                // updating expected, so do it here.
+                let func = self.current_func();
+                let previous_var = func.new_local(None, cmp.get_type(), "previous_value");
+                let success_var = func.new_local(None, self.bool_type, "success");
+                let previous_value = self.compare_and_swap16(dst, expected.to_rvalue(), src, "__sync_val_compare_and_swap_16");
+                self.llbb().add_assignment(None, previous_var, self.context.new_cast(None, previous_value, cmp.get_type()));
+                let success = self.context.new_comparison(None, ComparisonOp::Equals, previous_var.to_rvalue(), expected.to_rvalue());
+                self.llbb().add_assignment(None, success_var, success);
+                self.llbb().add_assignment(None, expected, previous_var.to_rvalue());
+                success_var.to_rvalue()
+            }
+            else {
+                self.compare_exchange(dst, expected, src, order, failure_order, weak)
+            };
 
         let pair_type = self.cx.type_struct(&[src.get_type(), self.bool_type], false);
         let result = self.current_func().new_local(None, pair_type, "atomic_cmpxchg_result");
@@ -1170,24 +1481,28 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn atomic_rmw(&mut self, op: AtomicRmwBinOp, dst: RValue<'gcc>, src: RValue<'gcc>, order: AtomicOrdering) -> RValue<'gcc> {
-        let size = src.get_type().get_size();
+        let src_type = src.get_type();
+        let size = self.atomic_size(src_type);
         let name =
             match op {
-                AtomicRmwBinOp::AtomicXchg => format!("__atomic_exchange_{}", size),
-                AtomicRmwBinOp::AtomicAdd => format!("__atomic_fetch_add_{}", size),
-                AtomicRmwBinOp::AtomicSub => format!("__atomic_fetch_sub_{}", size),
-                AtomicRmwBinOp::AtomicAnd => format!("__atomic_fetch_and_{}", size),
-                AtomicRmwBinOp::AtomicNand => format!("__atomic_fetch_nand_{}", size),
-                AtomicRmwBinOp::AtomicOr => format!("__atomic_fetch_or_{}", size),
-                AtomicRmwBinOp::AtomicXor => format!("__atomic_fetch_xor_{}", size),
-                AtomicRmwBinOp::AtomicMax => return self.atomic_extremum(ExtremumOperation::Max, dst, src, order),
-                AtomicRmwBinOp::AtomicMin => return self.atomic_extremum(ExtremumOperation::Min, dst, src, order),
-                AtomicRmwBinOp::AtomicUMax => return self.atomic_extremum(ExtremumOperation::Max, dst, src, order),
-                AtomicRmwBinOp::AtomicUMin => return self.atomic_extremum(ExtremumOperation::Min, dst, src, order),
+                AtomicRmwBinOp::AtomicXchg => "__atomic_exchange",
+                AtomicRmwBinOp::AtomicAdd => "__atomic_fetch_add",
+                AtomicRmwBinOp::AtomicSub => "__atomic_fetch_sub",
+                AtomicRmwBinOp::AtomicAnd => "__atomic_fetch_and",
+                AtomicRmwBinOp::AtomicNand => "__atomic_fetch_nand",
+                AtomicRmwBinOp::AtomicOr => "__atomic_fetch_or",
+                AtomicRmwBinOp::AtomicXor => "__atomic_fetch_xor",
+                AtomicRmwBinOp::AtomicMax => return self.atomic_extremum(ExtremumOperation::Max, dst, src, order, false),
+                AtomicRmwBinOp::AtomicMin => return self.atomic_extremum(ExtremumOperation::Min, dst, src, order, false),
+                AtomicRmwBinOp::AtomicUMax => return self.atomic_extremum(ExtremumOperation::Max, dst, src, order, true),
+                AtomicRmwBinOp::AtomicUMin => return self.atomic_extremum(ExtremumOperation::Min, dst, src, order, true),
             };
 
+        if size == 16 {
+            return self.atomic_cas_loop16(dst, src_type, |builder, previous| builder.atomic_rmw_value(&op, previous, src));
+        }
 
-        let atomic_function = self.context.get_builtin_function(name);
+        let atomic_function = self.context.get_builtin_function(&format!("{}_{}", name, size));
         let order = self.context.new_rvalue_from_int(self.i32_type, order.to_gcc());
 
         let void_ptr_type = self.context.new_type::<*mut ()>();
@@ -1197,7 +1512,7 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
         let new_src_type = atomic_function.get_param(1).to_rvalue().get_type();
         let src = self.context.new_cast(None, src, new_src_type);
         let res = self.context.new_call(None, atomic_function, &[dst, src, order]);
-        self.context.new_cast(None, res, src.get_type())
+        self.context.new_cast(None, res, src_type)
     }
 
     fn atomic_fence(&mut self, order: AtomicOrdering, scope: SynchronizationScope) {
@@ -1238,12 +1553,13 @@ impl<'a, 'gcc, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn zext(&mut self, value: RValue<'gcc>, dest_typ: Type<'gcc>) -> RValue<'gcc> {
-        // FIXME(antoyo): this does not zero-extend.
         if value.get_type().is_bool() && dest_typ.is_i8(&self.cx) {
             // FIXME(antoyo): hack because base::from_immediate converts i1 to i8.
             // Fix the code in codegen_ssa::base::from_immediate.
             return value;
         }
+        // NOTE: casting from an unsigned type is what zero extends the value.
+        let value = self.gcc_int_cast(value, value.get_type().to_unsigned(self));
         self.gcc_int_cast(value, dest_typ)
     }
 
@@ -1448,9 +1764,11 @@ impl ToGccOrdering for AtomicOrdering {
 
         let ordering =
             match self {
-                AtomicOrdering::NotAtomic => __ATOMIC_RELAXED, // TODO(antoyo): check if that's the same.
-                AtomicOrdering::Unordered => __ATOMIC_RELAXED,
-                AtomicOrdering::Monotonic => __ATOMIC_RELAXED, // TODO(antoyo): check if that's the same.
+                // NOTE: GCC has no ordering weaker than relaxed. Relaxed accesses do not tear, so
+                // they give at least the guarantees of LLVM's unordered.
+                AtomicOrdering::NotAtomic | AtomicOrdering::Unordered => __ATOMIC_RELAXED,
+                // NOTE: LLVM's monotonic is the C++ relaxed ordering.
+                AtomicOrdering::Monotonic => __ATOMIC_RELAXED,
                 AtomicOrdering::Acquire => __ATOMIC_ACQUIRE,
                 AtomicOrdering::Release => __ATOMIC_RELEASE,
                 AtomicOrdering::AcquireRelease => __ATOMIC_ACQ_REL,
@@ -1459,3 +1777,13 @@ impl ToGccOrdering for AtomicOrdering {
         ordering as i32
     }
 }
+
+/// GCC rejects release orderings on loads, including the load of a failed compare-exchange, so
+/// keep only their acquire part.
+fn to_load_ordering(order: AtomicOrdering) -> AtomicOrdering {
+    match order {
+        AtomicOrdering::Release => AtomicOrdering::Monotonic,
+        AtomicOrdering::AcquireRelease => AtomicOrdering::Acquire,
+        _ => order,
+    }
+}
@@ -14,7 +14,7 @@ use rustc_middle::ty::{self, Instance, ParamEnv, PolyExistentialTraitRef, Ty, Ty
 use rustc_middle::ty::layout::{FnAbiError, FnAbiOfHelpers, FnAbiRequest, HasParamEnv, HasTyCtxt, LayoutError, TyAndLayout, LayoutOfHelpers};
 use rustc_session::Session;
 use rustc_span::{Span, Symbol};
-use rustc_target::abi::{call::FnAbi, HasDataLayout, PointeeInfo, Size, TargetDataLayout, VariantIdx};
+use rustc_target::abi::{call::FnAbi, AbiAndPrefAlign, HasDataLayout, PointeeInfo, Size, TargetDataLayout, VariantIdx};
 use rustc_target::spec::{HasTargetSpec, Target, TlsModel};
 
 use crate::callee::get_fn;
@@ -86,6 +86,8 @@ pub struct CodegenCx<'gcc, 'tcx> {
     pub on_stack_params: RefCell<FxHashMap<FunctionPtrType<'gcc>, FxHashSet<usize>>>,
     // Mapping from function to indexes of on stack parameters.
     pub on_stack_function_params: RefCell<FxHashMap<Function<'gcc>, FxHashSet<usize>>>,
+    // Mapping from the GCC builtins implementing LLVM intrinsics to their name.
+    pub intrinsic_builtins: RefCell<FxHashMap<Function<'gcc>, String>>,
 
     /// Cache of emitted const globals (value -> global)
     pub const_globals: RefCell<FxHashMap<RValue<'gcc>, RValue<'gcc>>>,
@@ -128,13 +130,26 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
         let u32_type = context.new_c_type(CType::UInt32t);
         let u64_type = context.new_c_type(CType::UInt64t);
 
+        let data_layout = tcx.data_layout();
         let (i128_type, u128_type) =
             if supports_128bit_integers {
-                let i128_type = context.new_c_type(CType::Int128t).get_aligned(8); // TODO(antoyo): should the alignment be hard-coded?;
-                let u128_type = context.new_c_type(CType::UInt128t).get_aligned(8); // TODO(antoyo): should the alignment be hard-coded?;
+                // NOTE: GCC aligns __int128 on 16 bytes while rustc's data layout might use another
+                // alignment, so use the alignment of the data layout.
+                let align = data_layout.i128_align.abi.bytes();
+                let i128_type = context.new_c_type(CType::Int128t).get_aligned(align);
+                let u128_type = context.new_c_type(CType::UInt128t).get_aligned(align);
                 (i128_type, u128_type)
             }
             else {
+                // NOTE: the alignment of an array is the one of its elements and an aligned array
+                // type is not an array type anymore for libgccjit, so the layout of the array must
+                // already be the one of the data layout.
+                if data_layout.i128_align.abi != data_layout.i64_align.abi {
+                    tcx.sess.fatal(&format!(
+                        "128-bit integers are aligned on {} bytes on this target, which cannot be represented without a libgccjit that supports 128-bit integers",
+                        data_layout.i128_align.abi.bytes(),
+                    ));
+                }
                 let i128_type = context.new_array_type(None, i64_type, 2);
                 let u128_type = context.new_array_type(None, u64_type, 2);
                 (i128_type, u128_type)
@@ -152,13 +167,52 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
         let ulonglong_type = context.new_c_type(CType::ULongLong);
         let sizet_type = context.new_c_type(CType::SizeT);
 
-        let isize_type = context.new_c_type(CType::LongLong);
-        let usize_type = context.new_c_type(CType::ULongLong);
+        let (isize_type, usize_type) =
+            match data_layout.pointer_size.bits() {
+                16 => (context.new_c_type(CType::Short), context.new_c_type(CType::UShort)),
+                32 => (context.new_c_type(CType::Int), context.new_c_type(CType::UInt)),
+                64 => (context.new_c_type(CType::LongLong), context.new_c_type(CType::ULongLong)),
+                bits => tcx.sess.fatal(&format!("unsupported pointer width: {} bits", bits)),
+            };
         let bool_type = context.new_type::<bool>();
 
-        // TODO(antoyo): only have those assertions on x86_64.
-        assert_eq!(isize_type.get_size(), i64_type.get_size());
-        assert_eq!(usize_type.get_size(), u64_type.get_size());
+        // NOTE: libgccjit cannot give the alignment of a type, so only the size of the integer
+        // types is checked and their alignment is set from the data layout below.
+        let integer_types = [
+            (i8_type, Size::from_bytes(1)),
+            (i16_type, Size::from_bytes(2)),
+            (i32_type, Size::from_bytes(4)),
+            (i64_type, Size::from_bytes(8)),
+            (isize_type, data_layout.pointer_size),
+        ];
+        for &(typ, size) in integer_types.iter() {
+            if typ.get_size() as u64 != size.bytes() {
+                tcx.sess.fatal(&format!("GCC's {}-bit integer type has a size of {} bytes on this target", size.bits(), typ.get_size()));
+            }
+        }
+
+        // NOTE: GCC gives the integer, floating-point and pointer types their natural alignment,
+        // while rustc's data layout can give them a smaller one (e.g. 4 bytes for the 64-bit
+        // integers and f64 on i686), which GCC only uses for struct fields. So use the alignment
+        // of the data layout in this case.
+        let aligned = |typ: Type<'gcc>, size: u64, align: AbiAndPrefAlign| {
+            if align.abi.bytes() < size {
+                typ.get_aligned(align.abi.bytes())
+            }
+            else {
+                typ
+            }
+        };
+        let i16_type = aligned(i16_type, 2, data_layout.i16_align);
+        let i32_type = aligned(i32_type, 4, data_layout.i32_align);
+        let i64_type = aligned(i64_type, 8, data_layout.i64_align);
+        let u16_type = aligned(u16_type, 2, data_layout.i16_align);
+        let u32_type = aligned(u32_type, 4, data_layout.i32_align);
+        let u64_type = aligned(u64_type, 8, data_layout.i64_align);
+        let isize_type = aligned(isize_type, data_layout.pointer_size.bytes(), data_layout.pointer_align);
+        let usize_type = aligned(usize_type, data_layout.pointer_size.bytes(), data_layout.pointer_align);
+        let float_type = aligned(float_type, 4, data_layout.f32_align);
+        let double_type = aligned(double_type, 8, data_layout.f64_align);
 
         let mut functions = FxHashMap::default();
         let builtins = [
@@ -217,6 +271,7 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
             function_instances: Default::default(),
             on_stack_params: Default::default(),
             on_stack_function_params: Default::default(),
+            intrinsic_builtins: Default::default(),
             vtables: Default::default(),
             const_globals: Default::default(),
             global_lvalues: Default::default(),
@@ -387,7 +442,7 @@ impl<'gcc, 'tcx> MiscMethods<'tcx> for CodegenCx<'gcc, 'tcx> {
     }
 
     fn set_frame_pointer_type(&self, _llfn: RValue<'gcc>) {
-        // TODO(antoyo)
+        // NOTE: the frame pointer policy is set for the whole module in base::add_frame_options().
     }
 
     fn apply_target_cpu_attr(&self, _llfn: RValue<'gcc>) {
@@ -12,7 +12,7 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
     pub fn get_or_insert_global(&self, name: &str, ty: Type<'gcc>, is_tls: bool, link_section: Option<Symbol>) -> LValue<'gcc> {
         if self.globals.borrow().contains_key(name) {
             let typ = self.globals.borrow().get(name).expect("global").get_type();
-            let global = self.context.new_global(None, GlobalKind::Imported, typ, name);
+            let global = self.context.new_global(None, GlobalKind::Imported, typ, mangle_name(name));
             if is_tls {
                 global.set_tls_model(self.tls_model);
             }
@@ -32,7 +32,7 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
     }
 
     pub fn declare_global_with_linkage(&self, name: &str, ty: Type<'gcc>, linkage: GlobalKind) -> LValue<'gcc> {
-        let global = self.context.new_global(None, linkage, ty, name);
+        let global = self.context.new_global(None, linkage, ty, mangle_name(name));
         let global_address = global.get_address(None);
         self.globals.borrow_mut().insert(name.to_string(), global_address);
         global
@@ -46,7 +46,7 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
     }*/
 
     pub fn declare_global(&self, name: &str, ty: Type<'gcc>, global_kind: GlobalKind, is_tls: bool, link_section: Option<Symbol>) -> LValue<'gcc> {
-        let global = self.context.new_global(None, global_kind, ty, name);
+        let global = self.context.new_global(None, global_kind, ty, mangle_name(name));
         if is_tls {
             global.set_tls_model(self.tls_model);
         }
@@ -120,9 +120,9 @@ fn declare_raw_fn<'gcc>(cx: &CodegenCx<'gcc, '_>, name: &str, _callconv: () /*ll
 
     // TODO(antoyo): set function calling convention.
     // TODO(antoyo): set unnamed address.
-    // TODO(antoyo): set no red zone function attribute.
+    // NOTE: the no red zone and non lazy bind policies are set for the whole module in
+    // base::add_frame_options().
     // TODO(antoyo): set attributes for optimisation.
-    // TODO(antoyo): set attributes for non lazy bind.
 
     // FIXME(antoyo): invalid cast.
     func
@@ -130,6 +130,8 @@ fn declare_raw_fn<'gcc>(cx: &CodegenCx<'gcc, '_>, name: &str, _callconv: () /*ll
 
 // FIXME(antoyo): this is a hack because libgccjit currently only supports alpha, num and _.
 // Unsupported characters: `$` and `.`.
+// NOTE: the named globals, apart from the private ones, are mangled the same way, so that the asm
+// can refer to the functions and the statics with the same name.
 pub fn mangle_name(name: &str) -> String {
     name.replace(|char: char| {
         if !char.is_alphanumeric() && char != '_' {
@@ -8,6 +8,7 @@ use gccjit::{ComparisonOp, FunctionType, RValue, ToRValue, Type, UnaryOp, Binary
 use rustc_codegen_ssa::common::{IntPredicate, TypeKind};
 use rustc_codegen_ssa::traits::{BackendTypes, BaseTypeMethods, BuilderMethods, OverflowOp};
 use rustc_middle::ty::Ty;
+use rustc_target::abi::Endian;
 
 use crate::builder::ToGccComp;
 use crate::{builder::Builder, common::{SignType, TypeReflection}, context::CodegenCx};
@@ -38,11 +39,11 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
         else {
             // TODO(antoyo): use __negdi2 and __negti2 instead?
             let element_type = typ.dyncast_array().expect("element type");
-            let values = [
+            self.from_low_high_rvalues(
+                typ,
                 self.cx.context.new_unary_op(None, UnaryOp::BitwiseNegate, element_type, self.low(a)),
                 self.cx.context.new_unary_op(None, UnaryOp::BitwiseNegate, element_type, self.high(a)),
-            ];
-            self.cx.context.new_array_constructor(None, typ, &values)
+            )
         }
     }
 
@@ -52,9 +53,22 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
             self.cx.context.new_unary_op(None, UnaryOp::Minus, a.get_type(), a)
         }
         else {
-            let param_a = self.context.new_parameter(None, a_type, "a");
-            let func = self.context.new_function(None, FunctionType::Extern, a_type, &[param_a], "__negti2", false);
-            self.context.new_call(None, func, &[a])
+            // NOTE: negate the halves instead of calling __negti2, which is not available on
+            // targets where GCC does not support 128-bit integers.
+            // The operations are done on unsigned integers to have a wrapping behavior.
+            let native_int_type = a_type.dyncast_array().expect("get element type");
+            let unsigned_type = native_int_type.to_unsigned(self.cx);
+            let low = self.context.new_cast(None, self.low(a), unsigned_type);
+            let high = self.context.new_cast(None, self.high(a), unsigned_type);
+            let negated_low = self.context.new_unary_op(None, UnaryOp::Minus, unsigned_type, low);
+            let low_is_zero = self.context.new_comparison(None, ComparisonOp::Equals, low, self.context.new_rvalue_zero(unsigned_type));
+            let carry = self.context.new_cast(None, low_is_zero, unsigned_type);
+            let negated_high = self.context.new_unary_op(None, UnaryOp::BitwiseNegate, unsigned_type, high) + carry;
+            self.from_low_high_rvalues(
+                a_type,
+                self.context.new_cast(None, negated_low, native_int_type),
+                self.context.new_cast(None, negated_high, native_int_type),
+            )
         }
     }
 
@@ -103,7 +117,6 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
             let condition = self.gcc_icmp(IntPredicate::IntNE, self.gcc_and(b, sixty_four), zero);
             self.llbb().end_with_conditional(None, condition, then_block, else_block);
 
-            // TODO(antoyo): take endianness into account.
             let shift_value = self.gcc_sub(b, sixty_four);
             let high = self.high(a);
             let sign =
@@ -113,11 +126,7 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
                 else {
                     zero
                 };
-            let values = [
-                high >> shift_value,
-                sign,
-            ];
-            let array_value = self.context.new_array_constructor(None, a_type, &values);
+            let array_value = self.from_low_high_rvalues(a_type, high >> shift_value, sign);
             then_block.add_assignment(None, result, array_value);
             then_block.end_with_jump(None, after_block);
 
@@ -133,11 +142,11 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
             let casted_low = self.context.new_cast(None, self.low(a), unsigned_type);
             let shifted_low = casted_low >> self.context.new_cast(None, b, unsigned_type);
             let shifted_low = self.context.new_cast(None, shifted_low, native_int_type);
-            let values = [
+            let array_value = self.from_low_high_rvalues(
+                a_type,
                 (high << shift_value) | shifted_low,
                 high >> b,
-            ];
-            let array_value = self.context.new_array_constructor(None, a_type, &values);
+            );
             actual_else_block.add_assignment(None, result, array_value);
             actual_else_block.end_with_jump(None, after_block);
 
@@ -317,18 +326,7 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
                                         _ => unreachable!(),
                                     },
                             };
-                        let a_type = lhs.get_type();
-                        let b_type = rhs.get_type();
-                        let param_a = self.context.new_parameter(None, a_type, "a");
-                        let param_b = self.context.new_parameter(None, b_type, "b");
-                        let result_field = self.context.new_field(None, a_type, "result");
-                        let overflow_field = self.context.new_field(None, self.bool_type, "overflow");
-                        let return_type = self.context.new_struct_type(None, "result_overflow", &[result_field, overflow_field]);
-                        let func = self.context.new_function(None, FunctionType::Extern, return_type.as_type(), &[param_a, param_b], func_name, false);
-                        let result = self.context.new_call(None, func, &[lhs, rhs]);
-                        let overflow = result.access_field(None, overflow_field);
-                        let int_result = result.access_field(None, result_field);
-                        return (int_result, overflow);
+                        return self.non_native_overflow_call(func_name, lhs, rhs);
                     },
                     _ => {
                         match oop {
@@ -353,41 +351,73 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
         (res.dereference(None).to_rvalue(), overflow)
     }
 
+    /// Calls a compiler-builtins function doing an operation on non-native integers and returns
+    /// its result along with whether it overflowed.
+    pub fn non_native_overflow_call(&self, func_name: &str, lhs: RValue<'gcc>, rhs: RValue<'gcc>) -> (RValue<'gcc>, RValue<'gcc>) {
+        let a_type = lhs.get_type();
+        let b_type = rhs.get_type();
+        let param_a = self.context.new_parameter(None, a_type, "a");
+        let param_b = self.context.new_parameter(None, b_type, "b");
+        let result_field = self.context.new_field(None, a_type, "result");
+        let overflow_field = self.context.new_field(None, self.bool_type, "overflow");
+        let return_type = self.context.new_struct_type(None, "result_overflow", &[result_field, overflow_field]);
+        let func = self.context.new_function(None, FunctionType::Extern, return_type.as_type(), &[param_a, param_b], func_name, false);
+        // NOTE: assign the result to a local so that the function is only called once.
+        let result = self.current_func().new_local(None, return_type.as_type(), "overflow_result");
+        self.llbb().add_assignment(None, result, self.context.new_call(None, func, &[lhs, rhs]));
+        let result = result.to_rvalue();
+        (result.access_field(None, result_field), result.access_field(None, overflow_field))
+    }
+
     pub fn gcc_icmp(&self, op: IntPredicate, mut lhs: RValue<'gcc>, mut rhs: RValue<'gcc>) -> RValue<'gcc> {
         let a_type = lhs.get_type();
         let b_type = rhs.get_type();
         if self.is_non_native_int_type(a_type) || self.is_non_native_int_type(b_type) {
-            let signed = a_type.is_compatible_with(self.i128_type);
-            let sign =
-                if signed {
-                    ""
+            // NOTE: compare the halves instead of calling __cmpti2 and __ucmpti2, which are not
+            // available on targets where GCC does not support 128-bit integers.
+            let typ =
+                if self.is_non_native_int_type(a_type) {
+                    rhs = self.gcc_int_cast(rhs, a_type);
+                    a_type
                 }
                 else {
-                    "u"
+                    lhs = self.gcc_int_cast(lhs, b_type);
+                    b_type
                 };
-            let func_name = format!("__{}cmpti2", sign);
-            let param_a = self.context.new_parameter(None, a_type, "a");
-            let param_b = self.context.new_parameter(None, b_type, "b");
-            let func = self.context.new_function(None, FunctionType::Extern, self.int_type, &[param_a, param_b], func_name, false);
-            let cmp = self.context.new_call(None, func, &[lhs, rhs]);
-            let (op, limit) =
+            let native_int_type = typ.dyncast_array().expect("get element type");
+            let unsigned_type = native_int_type.to_unsigned(self.cx);
+            let signed_type = native_int_type.to_signed(self.cx);
+            let low_lhs = self.context.new_cast(None, self.low(lhs), unsigned_type);
+            let low_rhs = self.context.new_cast(None, self.low(rhs), unsigned_type);
+            let (high_type, high_op, low_op) =
                 match op {
-                    IntPredicate::IntEQ => {
-                        return self.context.new_comparison(None, ComparisonOp::Equals, cmp, self.context.new_rvalue_one(self.int_type));
-                    },
-                    IntPredicate::IntNE => {
-                        return self.context.new_comparison(None, ComparisonOp::NotEquals, cmp, self.context.new_rvalue_one(self.int_type));
+                    IntPredicate::IntEQ | IntPredicate::IntNE => {
+                        let (comparison, operation) =
+                            match op {
+                                IntPredicate::IntEQ => (ComparisonOp::Equals, BinaryOp::LogicalAnd),
+                                _ => (ComparisonOp::NotEquals, BinaryOp::LogicalOr),
+                            };
+                        let low = self.context.new_comparison(None, comparison, low_lhs, low_rhs);
+                        let high = self.context.new_comparison(None, comparison, self.high(lhs), self.high(rhs));
+                        return self.context.new_binary_op(None, operation, self.bool_type, low, high);
                     },
-                    IntPredicate::IntUGT => (ComparisonOp::Equals, 2),
-                    IntPredicate::IntUGE => (ComparisonOp::GreaterThanEquals, 1),
-                    IntPredicate::IntULT => (ComparisonOp::Equals, 0),
-                    IntPredicate::IntULE => (ComparisonOp::LessThanEquals, 1),
-                    IntPredicate::IntSGT => (ComparisonOp::Equals, 2),
-                    IntPredicate::IntSGE => (ComparisonOp::GreaterThanEquals, 1),
-                    IntPredicate::IntSLT => (ComparisonOp::Equals, 0),
-                    IntPredicate::IntSLE => (ComparisonOp::LessThanEquals, 1),
+                    IntPredicate::IntUGT => (unsigned_type, ComparisonOp::GreaterThan, ComparisonOp::GreaterThan),
+                    IntPredicate::IntUGE => (unsigned_type, ComparisonOp::GreaterThan, ComparisonOp::GreaterThanEquals),
+                    IntPredicate::IntULT => (unsigned_type, ComparisonOp::LessThan, ComparisonOp::LessThan),
+                    IntPredicate::IntULE => (unsigned_type, ComparisonOp::LessThan, ComparisonOp::LessThanEquals),
+                    IntPredicate::IntSGT => (signed_type, ComparisonOp::GreaterThan, ComparisonOp::GreaterThan),
+                    IntPredicate::IntSGE => (signed_type, ComparisonOp::GreaterThan, ComparisonOp::GreaterThanEquals),
+                    IntPredicate::IntSLT => (signed_type, ComparisonOp::LessThan, ComparisonOp::LessThan),
+                    IntPredicate::IntSLE => (signed_type, ComparisonOp::LessThan, ComparisonOp::LessThanEquals),
                 };
-            self.context.new_comparison(None, op, cmp, self.context.new_rvalue_from_int(self.int_type, limit))
+            // NOTE: only the high halves carry the sign.
+            let high_lhs = self.context.new_cast(None, self.high(lhs), high_type);
+            let high_rhs = self.context.new_cast(None, self.high(rhs), high_type);
+            let high_comparison = self.context.new_comparison(None, high_op, high_lhs, high_rhs);
+            let high_equal = self.context.new_comparison(None, ComparisonOp::Equals, high_lhs, high_rhs);
+            let low_comparison = self.context.new_comparison(None, low_op, low_lhs, low_rhs);
+            let low_decides = self.context.new_binary_op(None, BinaryOp::LogicalAnd, self.bool_type, high_equal, low_comparison);
+            self.context.new_binary_op(None, BinaryOp::LogicalOr, self.bool_type, high_comparison, low_decides)
         }
         else {
             let left_type = lhs.get_type();
@@ -414,11 +444,7 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
             a ^ b
         }
         else {
-            let values = [
-                self.low(a) ^ self.low(b),
-                self.high(a) ^ self.high(b),
-            ];
-            self.context.new_array_constructor(None, a_type, &values)
+            self.from_low_high_rvalues(a_type, self.low(a) ^ self.low(b), self.high(a) ^ self.high(b))
         }
     }
 
@@ -464,12 +490,7 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
             let condition = self.gcc_icmp(IntPredicate::IntNE, self.gcc_and(b, sixty_four), zero);
             self.llbb().end_with_conditional(None, condition, then_block, else_block);
 
-            // TODO(antoyo): take endianness into account.
-            let values = [
-                zero,
-                self.low(a) << (b - sixty_four),
-            ];
-            let array_value = self.context.new_array_constructor(None, a_type, &values);
+            let array_value = self.from_low_high_rvalues(a_type, zero, self.low(a) << (b - sixty_four));
             then_block.add_assignment(None, result, array_value);
             then_block.end_with_jump(None, after_block);
 
@@ -484,12 +505,11 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
             let casted_low = self.context.new_cast(None, self.low(a), unsigned_type);
             let shift_value = self.context.new_cast(None, sixty_four - b, unsigned_type);
             let high_low = self.context.new_cast(None, casted_low >> shift_value, native_int_type);
-            let values = [
+            let array_value = self.from_low_high_rvalues(
+                a_type,
                 self.low(a) << b,
                 (self.high(a) << b) | high_low,
-            ];
-
-            let array_value = self.context.new_array_constructor(None, a_type, &values);
+            );
             actual_else_block.add_assignment(None, result, array_value);
             actual_else_block.end_with_jump(None, after_block);
 
@@ -505,16 +525,16 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
         let arg_type = arg.get_type();
         if !self.is_native_int_type(arg_type) {
             let native_int_type = arg_type.dyncast_array().expect("get element type");
-            let lsb = self.context.new_array_access(None, arg, self.context.new_rvalue_from_int(self.int_type, 0)).to_rvalue();
+            let lsb = self.low(arg);
             let swapped_lsb = self.gcc_bswap(lsb, width / 2);
             let swapped_lsb = self.context.new_cast(None, swapped_lsb, native_int_type);
-            let msb = self.context.new_array_access(None, arg, self.context.new_rvalue_from_int(self.int_type, 1)).to_rvalue();
+            let msb = self.high(arg);
             let swapped_msb = self.gcc_bswap(msb, width / 2);
             let swapped_msb = self.context.new_cast(None, swapped_msb, native_int_type);
 
-            // NOTE: we also need to swap the two elements here, in addition to swapping inside
-            // the elements themselves like done above.
-            return self.context.new_array_constructor(None, arg_type, &[swapped_msb, swapped_lsb]);
+            // NOTE: we also need to swap the two halves here, in addition to swapping inside
+            // the halves themselves like done above.
+            return self.from_low_high_rvalues(arg_type, swapped_msb, swapped_lsb);
         }
 
         // TODO(antoyo): check if it's faster to use string literals and a
@@ -612,11 +632,11 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
         else {
             assert!(!a_native && !b_native, "both types should either be native or non-native for or operation");
             let native_int_type = a_type.dyncast_array().expect("get element type");
-            let values = [
+            self.from_low_high_rvalues(
+                a_type,
                 self.context.new_binary_op(None, operation, native_int_type, self.low(a), self.low(b)),
                 self.context.new_binary_op(None, operation, native_int_type, self.high(a), self.high(b)),
-            ];
-            self.context.new_array_constructor(None, a_type, &values)
+            )
         }
     }
 
@@ -640,11 +660,11 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
             let zero = self.context.new_rvalue_zero(value_type);
             let is_negative = self.context.new_comparison(None, ComparisonOp::LessThan, value, zero);
             let is_negative = self.gcc_int_cast(is_negative, dest_element_type);
-            let values = [
+            self.from_low_high_rvalues(
+                dest_typ,
                 self.context.new_cast(None, value, dest_element_type),
                 self.context.new_unary_op(None, UnaryOp::Minus, dest_element_type, is_negative),
-            ];
-            self.context.new_array_constructor(None, dest_typ, &values)
+            )
         }
         else {
             // Since u128 and i128 are the only types that can be unsupported, we know the type of
@@ -721,22 +741,38 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
         self.float_to_int_cast(false, value, dest_typ)
     }
 
+    /// Index of the most significant half of a non-native integer.
+    /// The halves are stored in the same order as the bytes of a native integer of the target.
+    fn high_index(&self) -> i32 {
+        match self.tcx.sess.target.endian {
+            Endian::Little => 1,
+            Endian::Big => 0,
+        }
+    }
+
     fn high(&self, value: RValue<'gcc>) -> RValue<'gcc> {
-        self.context.new_array_access(None, value, self.context.new_rvalue_from_int(self.int_type, 1))
+        self.context.new_array_access(None, value, self.context.new_rvalue_from_int(self.int_type, self.high_index()))
             .to_rvalue()
     }
 
     fn low(&self, value: RValue<'gcc>) -> RValue<'gcc> {
-        self.context.new_array_access(None, value, self.context.new_rvalue_from_int(self.int_type, 0))
+        self.context.new_array_access(None, value, self.context.new_rvalue_from_int(self.int_type, 1 - self.high_index()))
             .to_rvalue()
     }
 
+    fn from_low_high_rvalues(&self, typ: Type<'gcc>, low: RValue<'gcc>, high: RValue<'gcc>) -> RValue<'gcc> {
+        let values =
+            match self.tcx.sess.target.endian {
+                Endian::Little => [low, high],
+                Endian::Big => [high, low],
+            };
+        self.context.new_array_constructor(None, typ, &values)
+    }
+
     fn from_low_high(&self, typ: Type<'gcc>, low: i64, high: i64) -> RValue<'gcc> {
         let native_int_type = typ.dyncast_array().expect("get element type");
-        let values = [
-            self.context.new_rvalue_from_long(native_int_type, low),
-            self.context.new_rvalue_from_long(native_int_type, high),
-        ];
-        self.context.new_array_constructor(None, typ, &values)
+        let low = self.context.new_rvalue_from_long(native_int_type, low);
+        let high = self.context.new_rvalue_from_long(native_int_type, high);
+        self.from_low_high_rvalues(typ, low, high)
     }
 }
@@ -0,0 +1,1083 @@
+// File generated by `tools/generate_intrinsics.py`
+// DO NOT EDIT IT!
+
+/// Returns the name of the GCC builtin equivalent to the LLVM intrinsic `name`, if any.
+pub fn llvm_to_gcc_builtin(name: &str) -> Option<&'static str> {
+    let gcc_name =
+        match name {
+            // x86
+            "llvm.x86.aesni.aesdec" => "__builtin_ia32_aesdec128",
+            "llvm.x86.aesni.aesdeclast" => "__builtin_ia32_aesdeclast128",
+            "llvm.x86.aesni.aesenc" => "__builtin_ia32_aesenc128",
+            "llvm.x86.aesni.aesenclast" => "__builtin_ia32_aesenclast128",
+            "llvm.x86.aesni.aesimc" => "__builtin_ia32_aesimc128",
+            "llvm.x86.aesni.aeskeygenassist" => "__builtin_ia32_aeskeygenassist128",
+            "llvm.x86.avx.addsub.pd.256" => "__builtin_ia32_addsubpd256",
+            "llvm.x86.avx.addsub.ps.256" => "__builtin_ia32_addsubps256",
+            "llvm.x86.avx.blendv.pd.256" => "__builtin_ia32_blendvpd256",
+            "llvm.x86.avx.blendv.ps.256" => "__builtin_ia32_blendvps256",
+            "llvm.x86.avx.cvt.pd2.ps.256" => "__builtin_ia32_cvtpd2ps256",
+            "llvm.x86.avx.cvt.pd2dq.256" => "__builtin_ia32_cvtpd2dq256",
+            "llvm.x86.avx.cvt.ps2dq.256" => "__builtin_ia32_cvtps2dq256",
+            "llvm.x86.avx.cvtt.pd2dq.256" => "__builtin_ia32_cvttpd2dq256",
+            "llvm.x86.avx.cvtt.ps2dq.256" => "__builtin_ia32_cvttps2dq256",
+            "llvm.x86.avx.dp.ps.256" => "__builtin_ia32_dpps256",
+            "llvm.x86.avx.hadd.pd.256" => "__builtin_ia32_haddpd256",
+            "llvm.x86.avx.hadd.ps.256" => "__builtin_ia32_haddps256",
+            "llvm.x86.avx.hsub.pd.256" => "__builtin_ia32_hsubpd256",
+            "llvm.x86.avx.hsub.ps.256" => "__builtin_ia32_hsubps256",
+            "llvm.x86.avx.ldu.dq.256" => "__builtin_ia32_lddqu256",
+            "llvm.x86.avx.maskload.pd" => "__builtin_ia32_maskloadpd",
+            "llvm.x86.avx.maskload.pd.256" => "__builtin_ia32_maskloadpd256",
+            "llvm.x86.avx.maskload.ps" => "__builtin_ia32_maskloadps",
+            "llvm.x86.avx.maskload.ps.256" => "__builtin_ia32_maskloadps256",
+            "llvm.x86.avx.maskstore.pd" => "__builtin_ia32_maskstorepd",
+            "llvm.x86.avx.maskstore.pd.256" => "__builtin_ia32_maskstorepd256",
+            "llvm.x86.avx.maskstore.ps" => "__builtin_ia32_maskstoreps",
+            "llvm.x86.avx.maskstore.ps.256" => "__builtin_ia32_maskstoreps256",
+            "llvm.x86.avx.max.pd.256" => "__builtin_ia32_maxpd256",
+            "llvm.x86.avx.max.ps.256" => "__builtin_ia32_maxps256",
+            "llvm.x86.avx.min.pd.256" => "__builtin_ia32_minpd256",
+            "llvm.x86.avx.min.ps.256" => "__builtin_ia32_minps256",
+            "llvm.x86.avx.movmsk.pd.256" => "__builtin_ia32_movmskpd256",
+            "llvm.x86.avx.movmsk.ps.256" => "__builtin_ia32_movmskps256",
+            "llvm.x86.avx.ptestc.256" => "__builtin_ia32_ptestc256",
+            "llvm.x86.avx.ptestnzc.256" => "__builtin_ia32_ptestnzc256",
+            "llvm.x86.avx.ptestz.256" => "__builtin_ia32_ptestz256",
+            "llvm.x86.avx.rcp.ps.256" => "__builtin_ia32_rcpps256",
+            "llvm.x86.avx.round.pd.256" => "__builtin_ia32_roundpd256",
+            "llvm.x86.avx.round.ps.256" => "__builtin_ia32_roundps256",
+            "llvm.x86.avx.rsqrt.ps.256" => "__builtin_ia32_rsqrtps256",
+            "llvm.x86.avx.vpermilvar.pd" => "__builtin_ia32_vpermilvarpd",
+            "llvm.x86.avx.vpermilvar.pd.256" => "__builtin_ia32_vpermilvarpd256",
+            "llvm.x86.avx.vpermilvar.ps" => "__builtin_ia32_vpermilvarps",
+            "llvm.x86.avx.vpermilvar.ps.256" => "__builtin_ia32_vpermilvarps256",
+            "llvm.x86.avx.vtestc.pd" => "__builtin_ia32_vtestcpd",
+            "llvm.x86.avx.vtestc.pd.256" => "__builtin_ia32_vtestcpd256",
+            "llvm.x86.avx.vtestc.ps" => "__builtin_ia32_vtestcps",
+            "llvm.x86.avx.vtestc.ps.256" => "__builtin_ia32_vtestcps256",
+            "llvm.x86.avx.vtestnzc.pd" => "__builtin_ia32_vtestnzcpd",
+            "llvm.x86.avx.vtestnzc.pd.256" => "__builtin_ia32_vtestnzcpd256",
+            "llvm.x86.avx.vtestnzc.ps" => "__builtin_ia32_vtestnzcps",
+            "llvm.x86.avx.vtestnzc.ps.256" => "__builtin_ia32_vtestnzcps256",
+            "llvm.x86.avx.vtestz.pd" => "__builtin_ia32_vtestzpd",
+            "llvm.x86.avx.vtestz.pd.256" => "__builtin_ia32_vtestzpd256",
+            "llvm.x86.avx.vtestz.ps" => "__builtin_ia32_vtestzps",
+            "llvm.x86.avx.vtestz.ps.256" => "__builtin_ia32_vtestzps256",
+            "llvm.x86.avx.vzeroall" => "__builtin_ia32_vzeroall",
+            "llvm.x86.avx.vzeroupper" => "__builtin_ia32_vzeroupper",
+            "llvm.x86.avx2.maskload.d" => "__builtin_ia32_maskloadd",
+            "llvm.x86.avx2.maskload.d.256" => "__builtin_ia32_maskloadd256",
+            "llvm.x86.avx2.maskload.q" => "__builtin_ia32_maskloadq",
+            "llvm.x86.avx2.maskload.q.256" => "__builtin_ia32_maskloadq256",
+            "llvm.x86.avx2.maskstore.d" => "__builtin_ia32_maskstored",
+            "llvm.x86.avx2.maskstore.d.256" => "__builtin_ia32_maskstored256",
+            "llvm.x86.avx2.maskstore.q" => "__builtin_ia32_maskstoreq",
+            "llvm.x86.avx2.maskstore.q.256" => "__builtin_ia32_maskstoreq256",
+            "llvm.x86.avx2.mpsadbw" => "__builtin_ia32_mpsadbw256",
+            "llvm.x86.avx2.packssdw" => "__builtin_ia32_packssdw256",
+            "llvm.x86.avx2.packsswb" => "__builtin_ia32_packsswb256",
+            "llvm.x86.avx2.packusdw" => "__builtin_ia32_packusdw256",
+            "llvm.x86.avx2.packuswb" => "__builtin_ia32_packuswb256",
+            "llvm.x86.avx2.pavg.b" => "__builtin_ia32_pavgb256",
+            "llvm.x86.avx2.pavg.w" => "__builtin_ia32_pavgw256",
+            "llvm.x86.avx2.pblendvb" => "__builtin_ia32_pblendvb256",
+            "llvm.x86.avx2.permd" => "__builtin_ia32_permvarsi256",
+            "llvm.x86.avx2.permps" => "__builtin_ia32_permvarsf256",
+            "llvm.x86.avx2.phadd.d" => "__builtin_ia32_phaddd256",
+            "llvm.x86.avx2.phadd.sw" => "__builtin_ia32_phaddsw256",
+            "llvm.x86.avx2.phadd.w" => "__builtin_ia32_phaddw256",
+            "llvm.x86.avx2.phsub.d" => "__builtin_ia32_phsubd256",
+            "llvm.x86.avx2.phsub.sw" => "__builtin_ia32_phsubsw256",
+            "llvm.x86.avx2.phsub.w" => "__builtin_ia32_phsubw256",
+            "llvm.x86.avx2.pmadd.ub.sw" => "__builtin_ia32_pmaddubsw256",
+            "llvm.x86.avx2.pmadd.wd" => "__builtin_ia32_pmaddwd256",
+            "llvm.x86.avx2.pmovmskb" => "__builtin_ia32_pmovmskb256",
+            "llvm.x86.avx2.pmul.hr.sw" => "__builtin_ia32_pmulhrsw256",
+            "llvm.x86.avx2.pmulh.w" => "__builtin_ia32_pmulhw256",
+            "llvm.x86.avx2.pmulhu.w" => "__builtin_ia32_pmulhuw256",
+            "llvm.x86.avx2.psad.bw" => "__builtin_ia32_psadbw256",
+            "llvm.x86.avx2.pshuf.b" => "__builtin_ia32_pshufb256",
+            "llvm.x86.avx2.psign.b" => "__builtin_ia32_psignb256",
+            "llvm.x86.avx2.psign.d" => "__builtin_ia32_psignd256",
+            "llvm.x86.avx2.psign.w" => "__builtin_ia32_psignw256",
+            "llvm.x86.avx2.psll.d" => "__builtin_ia32_pslld256",
+            "llvm.x86.avx2.psll.q" => "__builtin_ia32_psllq256",
+            "llvm.x86.avx2.psll.w" => "__builtin_ia32_psllw256",
+            "llvm.x86.avx2.pslli.d" => "__builtin_ia32_pslldi256",
+            "llvm.x86.avx2.pslli.q" => "__builtin_ia32_psllqi256",
+            "llvm.x86.avx2.pslli.w" => "__builtin_ia32_psllwi256",
+            "llvm.x86.avx2.psllv.d" => "__builtin_ia32_psllv4si",
+            "llvm.x86.avx2.psllv.d.256" => "__builtin_ia32_psllv8si",
+            "llvm.x86.avx2.psllv.q" => "__builtin_ia32_psllv2di",
+            "llvm.x86.avx2.psllv.q.256" => "__builtin_ia32_psllv4di",
+            "llvm.x86.avx2.psra.d" => "__builtin_ia32_psrad256",
+            "llvm.x86.avx2.psra.w" => "__builtin_ia32_psraw256",
+            "llvm.x86.avx2.psrai.d" => "__builtin_ia32_psradi256",
+            "llvm.x86.avx2.psrai.w" => "__builtin_ia32_psrawi256",
+            "llvm.x86.avx2.psrav.d" => "__builtin_ia32_psrav4si",
+            "llvm.x86.avx2.psrav.d.256" => "__builtin_ia32_psrav8si",
+            "llvm.x86.avx2.psrl.d" => "__builtin_ia32_psrld256",
+            "llvm.x86.avx2.psrl.q" => "__builtin_ia32_psrlq256",
+            "llvm.x86.avx2.psrl.w" => "__builtin_ia32_psrlw256",
+            "llvm.x86.avx2.psrli.d" => "__builtin_ia32_psrldi256",
+            "llvm.x86.avx2.psrli.q" => "__builtin_ia32_psrlqi256",
+            "llvm.x86.avx2.psrli.w" => "__builtin_ia32_psrlwi256",
+            "llvm.x86.avx2.psrlv.d" => "__builtin_ia32_psrlv4si",
+            "llvm.x86.avx2.psrlv.d.256" => "__builtin_ia32_psrlv8si",
+            "llvm.x86.avx2.psrlv.q" => "__builtin_ia32_psrlv2di",
+            "llvm.x86.avx2.psrlv.q.256" => "__builtin_ia32_psrlv4di",
+            "llvm.x86.avx512.add.pd.512" => "__builtin_ia32_addpd512_mask",
+            "llvm.x86.avx512.add.ps.512" => "__builtin_ia32_addps512_mask",
+            "llvm.x86.avx512.broadcastmb.128" => "__builtin_ia32_broadcastmb128",
+            "llvm.x86.avx512.broadcastmb.256" => "__builtin_ia32_broadcastmb256",
+            "llvm.x86.avx512.broadcastmb.512" => "__builtin_ia32_broadcastmb512",
+            "llvm.x86.avx512.broadcastmw.128" => "__builtin_ia32_broadcastmw128",
+            "llvm.x86.avx512.broadcastmw.256" => "__builtin_ia32_broadcastmw256",
+            "llvm.x86.avx512.broadcastmw.512" => "__builtin_ia32_broadcastmw512",
+            "llvm.x86.avx512.conflict.d.128" => "__builtin_ia32_vpconflictsi_128_mask",
+            "llvm.x86.avx512.conflict.d.256" => "__builtin_ia32_vpconflictsi_256_mask",
+            "llvm.x86.avx512.conflict.d.512" => "__builtin_ia32_vpconflictsi_512_mask",
+            "llvm.x86.avx512.conflict.q.128" => "__builtin_ia32_vpconflictdi_128_mask",
+            "llvm.x86.avx512.conflict.q.256" => "__builtin_ia32_vpconflictdi_256_mask",
+            "llvm.x86.avx512.conflict.q.512" => "__builtin_ia32_vpconflictdi_512_mask",
+            "llvm.x86.avx512.cvtsi2sd64" => "__builtin_ia32_cvtsi2sd64",
+            "llvm.x86.avx512.cvtsi2ss32" => "__builtin_ia32_cvtsi2ss32",
+            "llvm.x86.avx512.cvtsi2ss64" => "__builtin_ia32_cvtsi2ss64",
+            "llvm.x86.avx512.cvttsd2si" => "__builtin_ia32_vcvttsd2si32",
+            "llvm.x86.avx512.cvttsd2si64" => "__builtin_ia32_vcvttsd2si64",
+            "llvm.x86.avx512.cvttsd2usi" => "__builtin_ia32_vcvttsd2usi32",
+            "llvm.x86.avx512.cvttsd2usi64" => "__builtin_ia32_vcvttsd2usi64",
+            "llvm.x86.avx512.cvttss2si" => "__builtin_ia32_vcvttss2si32",
+            "llvm.x86.avx512.cvttss2si64" => "__builtin_ia32_vcvttss2si64",
+            "llvm.x86.avx512.cvttss2usi" => "__builtin_ia32_vcvttss2usi32",
+            "llvm.x86.avx512.cvttss2usi64" => "__builtin_ia32_vcvttss2usi64",
+            "llvm.x86.avx512.cvtusi2ss" => "__builtin_ia32_cvtusi2ss32",
+            "llvm.x86.avx512.cvtusi642sd" => "__builtin_ia32_cvtusi2sd64",
+            "llvm.x86.avx512.cvtusi642ss" => "__builtin_ia32_cvtusi2ss64",
+            "llvm.x86.avx512.dbpsadbw.128" => "__builtin_ia32_dbpsadbw128_mask",
+            "llvm.x86.avx512.dbpsadbw.256" => "__builtin_ia32_dbpsadbw256_mask",
+            "llvm.x86.avx512.dbpsadbw.512" => "__builtin_ia32_dbpsadbw512_mask",
+            "llvm.x86.avx512.div.pd.512" => "__builtin_ia32_divpd512_mask",
+            "llvm.x86.avx512.div.ps.512" => "__builtin_ia32_divps512_mask",
+            "llvm.x86.avx512.exp2.pd" => "__builtin_ia32_exp2pd_mask",
+            "llvm.x86.avx512.exp2.ps" => "__builtin_ia32_exp2ps_mask",
+            "llvm.x86.avx512.gatherpf.dpd.512" => "__builtin_ia32_gatherpfdpd",
+            "llvm.x86.avx512.gatherpf.dps.512" => "__builtin_ia32_gatherpfdps",
+            "llvm.x86.avx512.gatherpf.qpd.512" => "__builtin_ia32_gatherpfqpd",
+            "llvm.x86.avx512.gatherpf.qps.512" => "__builtin_ia32_gatherpfqps",
+            "llvm.x86.avx512.mask.add.sd.round" => "__builtin_ia32_addsd_mask_round",
+            "llvm.x86.avx512.mask.add.ss.round" => "__builtin_ia32_addss_mask_round",
+            "llvm.x86.avx512.mask.cmp.sd" => "__builtin_ia32_cmpsd_mask",
+            "llvm.x86.avx512.mask.cmp.ss" => "__builtin_ia32_cmpss_mask",
+            "llvm.x86.avx512.mask.cvtpd2dq.128" => "__builtin_ia32_cvtpd2dq128_mask",
+            "llvm.x86.avx512.mask.cvtpd2dq.512" => "__builtin_ia32_cvtpd2dq512_mask",
+            "llvm.x86.avx512.mask.cvtpd2ps" => "__builtin_ia32_cvtpd2ps_mask",
+            "llvm.x86.avx512.mask.cvtpd2ps.512" => "__builtin_ia32_cvtpd2ps512_mask",
+            "llvm.x86.avx512.mask.cvtpd2qq.128" => "__builtin_ia32_cvtpd2qq128_mask",
+            "llvm.x86.avx512.mask.cvtpd2qq.256" => "__builtin_ia32_cvtpd2qq256_mask",
+            "llvm.x86.avx512.mask.cvtpd2qq.512" => "__builtin_ia32_cvtpd2qq512_mask",
+            "llvm.x86.avx512.mask.cvtpd2udq.128" => "__builtin_ia32_cvtpd2udq128_mask",
+            "llvm.x86.avx512.mask.cvtpd2udq.256" => "__builtin_ia32_cvtpd2udq256_mask",
+            "llvm.x86.avx512.mask.cvtpd2udq.512" => "__builtin_ia32_cvtpd2udq512_mask",
+            "llvm.x86.avx512.mask.cvtpd2uqq.128" => "__builtin_ia32_cvtpd2uqq128_mask",
+            "llvm.x86.avx512.mask.cvtpd2uqq.256" => "__builtin_ia32_cvtpd2uqq256_mask",
+            "llvm.x86.avx512.mask.cvtpd2uqq.512" => "__builtin_ia32_cvtpd2uqq512_mask",
+            "llvm.x86.avx512.mask.cvtps2dq.128" => "__builtin_ia32_cvtps2dq128_mask",
+            "llvm.x86.avx512.mask.cvtps2dq.256" => "__builtin_ia32_cvtps2dq256_mask",
+            "llvm.x86.avx512.mask.cvtps2dq.512" => "__builtin_ia32_cvtps2dq512_mask",
+            "llvm.x86.avx512.mask.cvtps2pd.512" => "__builtin_ia32_cvtps2pd512_mask",
+            "llvm.x86.avx512.mask.cvtps2qq.128" => "__builtin_ia32_cvtps2qq128_mask",
+            "llvm.x86.avx512.mask.cvtps2qq.256" => "__builtin_ia32_cvtps2qq256_mask",
+            "llvm.x86.avx512.mask.cvtps2qq.512" => "__builtin_ia32_cvtps2qq512_mask",
+            "llvm.x86.avx512.mask.cvtps2udq.128" => "__builtin_ia32_cvtps2udq128_mask",
+            "llvm.x86.avx512.mask.cvtps2udq.256" => "__builtin_ia32_cvtps2udq256_mask",
+            "llvm.x86.avx512.mask.cvtps2udq.512" => "__builtin_ia32_cvtps2udq512_mask",
+            "llvm.x86.avx512.mask.cvtps2uqq.128" => "__builtin_ia32_cvtps2uqq128_mask",
+            "llvm.x86.avx512.mask.cvtps2uqq.256" => "__builtin_ia32_cvtps2uqq256_mask",
+            "llvm.x86.avx512.mask.cvtps2uqq.512" => "__builtin_ia32_cvtps2uqq512_mask",
+            "llvm.x86.avx512.mask.cvtqq2ps.128" => "__builtin_ia32_cvtqq2ps128_mask",
+            "llvm.x86.avx512.mask.cvtsd2ss.round" => "__builtin_ia32_cvtsd2ss_mask_round",
+            "llvm.x86.avx512.mask.cvtss2sd.round" => "__builtin_ia32_cvtss2sd_mask_round",
+            "llvm.x86.avx512.mask.cvttpd2dq.128" => "__builtin_ia32_cvttpd2dq128_mask",
+            "llvm.x86.avx512.mask.cvttpd2dq.512" => "__builtin_ia32_cvttpd2dq512_mask",
+            "llvm.x86.avx512.mask.cvttpd2qq.128" => "__builtin_ia32_cvttpd2qq128_mask",
+            "llvm.x86.avx512.mask.cvttpd2qq.256" => "__builtin_ia32_cvttpd2qq256_mask",
+            "llvm.x86.avx512.mask.cvttpd2qq.512" => "__builtin_ia32_cvttpd2qq512_mask",
+            "llvm.x86.avx512.mask.cvttpd2udq.128" => "__builtin_ia32_cvttpd2udq128_mask",
+            "llvm.x86.avx512.mask.cvttpd2udq.256" => "__builtin_ia32_cvttpd2udq256_mask",
+            "llvm.x86.avx512.mask.cvttpd2udq.512" => "__builtin_ia32_cvttpd2udq512_mask",
+            "llvm.x86.avx512.mask.cvttpd2uqq.128" => "__builtin_ia32_cvttpd2uqq128_mask",
+            "llvm.x86.avx512.mask.cvttpd2uqq.256" => "__builtin_ia32_cvttpd2uqq256_mask",
+            "llvm.x86.avx512.mask.cvttpd2uqq.512" => "__builtin_ia32_cvttpd2uqq512_mask",
+            "llvm.x86.avx512.mask.cvttps2dq.512" => "__builtin_ia32_cvttps2dq512_mask",
+            "llvm.x86.avx512.mask.cvttps2qq.128" => "__builtin_ia32_cvttps2qq128_mask",
+            "llvm.x86.avx512.mask.cvttps2qq.256" => "__builtin_ia32_cvttps2qq256_mask",
+            "llvm.x86.avx512.mask.cvttps2qq.512" => "__builtin_ia32_cvttps2qq512_mask",
+            "llvm.x86.avx512.mask.cvttps2udq.128" => "__builtin_ia32_cvttps2udq128_mask",
+            "llvm.x86.avx512.mask.cvttps2udq.256" => "__builtin_ia32_cvttps2udq256_mask",
+            "llvm.x86.avx512.mask.cvttps2udq.512" => "__builtin_ia32_cvttps2udq512_mask",
+            "llvm.x86.avx512.mask.cvttps2uqq.128" => "__builtin_ia32_cvttps2uqq128_mask",
+            "llvm.x86.avx512.mask.cvttps2uqq.256" => "__builtin_ia32_cvttps2uqq256_mask",
+            "llvm.x86.avx512.mask.cvttps2uqq.512" => "__builtin_ia32_cvttps2uqq512_mask",
+            "llvm.x86.avx512.mask.cvtuqq2ps.128" => "__builtin_ia32_cvtuqq2ps128_mask",
+            "llvm.x86.avx512.mask.div.sd.round" => "__builtin_ia32_divsd_mask_round",
+            "llvm.x86.avx512.mask.div.ss.round" => "__builtin_ia32_divss_mask_round",
+            "llvm.x86.avx512.mask.fixupimm.pd.128" => "__builtin_ia32_fixupimmpd128_mask",
+            "llvm.x86.avx512.mask.fixupimm.pd.256" => "__builtin_ia32_fixupimmpd256_mask",
+            "llvm.x86.avx512.mask.fixupimm.pd.512" => "__builtin_ia32_fixupimmpd512_mask",
+            "llvm.x86.avx512.mask.fixupimm.ps.128" => "__builtin_ia32_fixupimmps128_mask",
+            "llvm.x86.avx512.mask.fixupimm.ps.256" => "__builtin_ia32_fixupimmps256_mask",
+            "llvm.x86.avx512.mask.fixupimm.ps.512" => "__builtin_ia32_fixupimmps512_mask",
+            "llvm.x86.avx512.mask.fixupimm.sd" => "__builtin_ia32_fixupimmsd_mask",
+            "llvm.x86.avx512.mask.fixupimm.ss" => "__builtin_ia32_fixupimmss_mask",
+            "llvm.x86.avx512.mask.fpclass.sd" => "__builtin_ia32_fpclasssd_mask",
+            "llvm.x86.avx512.mask.fpclass.ss" => "__builtin_ia32_fpclassss_mask",
+            "llvm.x86.avx512.mask.getexp.pd.128" => "__builtin_ia32_getexppd128_mask",
+            "llvm.x86.avx512.mask.getexp.pd.256" => "__builtin_ia32_getexppd256_mask",
+            "llvm.x86.avx512.mask.getexp.pd.512" => "__builtin_ia32_getexppd512_mask",
+            "llvm.x86.avx512.mask.getexp.ps.128" => "__builtin_ia32_getexpps128_mask",
+            "llvm.x86.avx512.mask.getexp.ps.256" => "__builtin_ia32_getexpps256_mask",
+            "llvm.x86.avx512.mask.getexp.ps.512" => "__builtin_ia32_getexpps512_mask",
+            "llvm.x86.avx512.mask.getexp.sd" => "__builtin_ia32_getexpsd_mask_round",
+            "llvm.x86.avx512.mask.getexp.ss" => "__builtin_ia32_getexpss_mask_round",
+            "llvm.x86.avx512.mask.getmant.pd.128" => "__builtin_ia32_getmantpd128_mask",
+            "llvm.x86.avx512.mask.getmant.pd.256" => "__builtin_ia32_getmantpd256_mask",
+            "llvm.x86.avx512.mask.getmant.pd.512" => "__builtin_ia32_getmantpd512_mask",
+            "llvm.x86.avx512.mask.getmant.ps.128" => "__builtin_ia32_getmantps128_mask",
+            "llvm.x86.avx512.mask.getmant.ps.256" => "__builtin_ia32_getmantps256_mask",
+            "llvm.x86.avx512.mask.getmant.ps.512" => "__builtin_ia32_getmantps512_mask",
+            "llvm.x86.avx512.mask.getmant.sd" => "__builtin_ia32_getmantsd_mask_round",
+            "llvm.x86.avx512.mask.getmant.ss" => "__builtin_ia32_getmantss_mask_round",
+            "llvm.x86.avx512.mask.max.sd.round" => "__builtin_ia32_maxsd_mask_round",
+            "llvm.x86.avx512.mask.max.ss.round" => "__builtin_ia32_maxss_mask_round",
+            "llvm.x86.avx512.mask.min.sd.round" => "__builtin_ia32_minsd_mask_round",
+            "llvm.x86.avx512.mask.min.ss.round" => "__builtin_ia32_minss_mask_round",
+            "llvm.x86.avx512.mask.mul.sd.round" => "__builtin_ia32_mulsd_mask_round",
+            "llvm.x86.avx512.mask.mul.ss.round" => "__builtin_ia32_mulss_mask_round",
+            "llvm.x86.avx512.mask.pmov.db.128" => "__builtin_ia32_pmovdb128_mask",
+            "llvm.x86.avx512.mask.pmov.db.256" => "__builtin_ia32_pmovdb256_mask",
+            "llvm.x86.avx512.mask.pmov.db.mem.128" => "__builtin_ia32_pmovdb128mem_mask",
+            "llvm.x86.avx512.mask.pmov.db.mem.256" => "__builtin_ia32_pmovdb256mem_mask",
+            "llvm.x86.avx512.mask.pmov.db.mem.512" => "__builtin_ia32_pmovdb512mem_mask",
+            "llvm.x86.avx512.mask.pmov.dw.128" => "__builtin_ia32_pmovdw128_mask",
+            "llvm.x86.avx512.mask.pmov.dw.256" => "__builtin_ia32_pmovdw256_mask",
+            "llvm.x86.avx512.mask.pmov.dw.mem.128" => "__builtin_ia32_pmovdw128mem_mask",
+            "llvm.x86.avx512.mask.pmov.dw.mem.256" => "__builtin_ia32_pmovdw256mem_mask",
+            "llvm.x86.avx512.mask.pmov.dw.mem.512" => "__builtin_ia32_pmovdw512mem_mask",
+            "llvm.x86.avx512.mask.pmov.qb.128" => "__builtin_ia32_pmovqb128_mask",
+            "llvm.x86.avx512.mask.pmov.qb.256" => "__builtin_ia32_pmovqb256_mask",
+            "llvm.x86.avx512.mask.pmov.qb.512" => "__builtin_ia32_pmovqb512_mask",
+            "llvm.x86.avx512.mask.pmov.qb.mem.128" => "__builtin_ia32_pmovqb128mem_mask",
+            "llvm.x86.avx512.mask.pmov.qb.mem.256" => "__builtin_ia32_pmovqb256mem_mask",
+            "llvm.x86.avx512.mask.pmov.qb.mem.512" => "__builtin_ia32_pmovqb512mem_mask",
+            "llvm.x86.avx512.mask.pmov.qd.128" => "__builtin_ia32_pmovqd128_mask",
+            "llvm.x86.avx512.mask.pmov.qd.mem.128" => "__builtin_ia32_pmovqd128mem_mask",
+            "llvm.x86.avx512.mask.pmov.qd.mem.256" => "__builtin_ia32_pmovqd256mem_mask",
+            "llvm.x86.avx512.mask.pmov.qd.mem.512" => "__builtin_ia32_pmovqd512mem_mask",
+            "llvm.x86.avx512.mask.pmov.qw.128" => "__builtin_ia32_pmovqw128_mask",
+            "llvm.x86.avx512.mask.pmov.qw.256" => "__builtin_ia32_pmovqw256_mask",
+            "llvm.x86.avx512.mask.pmov.qw.mem.128" => "__builtin_ia32_pmovqw128mem_mask",
+            "llvm.x86.avx512.mask.pmov.qw.mem.256" => "__builtin_ia32_pmovqw256mem_mask",
+            "llvm.x86.avx512.mask.pmov.qw.mem.512" => "__builtin_ia32_pmovqw512mem_mask",
+            "llvm.x86.avx512.mask.pmov.wb.128" => "__builtin_ia32_pmovwb128_mask",
+            "llvm.x86.avx512.mask.pmov.wb.mem.128" => "__builtin_ia32_pmovwb128mem_mask",
+            "llvm.x86.avx512.mask.pmov.wb.mem.256" => "__builtin_ia32_pmovwb256mem_mask",
+            "llvm.x86.avx512.mask.pmov.wb.mem.512" => "__builtin_ia32_pmovwb512mem_mask",
+            "llvm.x86.avx512.mask.pmovs.db.128" => "__builtin_ia32_pmovsdb128_mask",
+            "llvm.x86.avx512.mask.pmovs.db.256" => "__builtin_ia32_pmovsdb256_mask",
+            "llvm.x86.avx512.mask.pmovs.db.512" => "__builtin_ia32_pmovsdb512_mask",
+            "llvm.x86.avx512.mask.pmovs.db.mem.128" => "__builtin_ia32_pmovsdb128mem_mask",
+            "llvm.x86.avx512.mask.pmovs.db.mem.256" => "__builtin_ia32_pmovsdb256mem_mask",
+            "llvm.x86.avx512.mask.pmovs.db.mem.512" => "__builtin_ia32_pmovsdb512mem_mask",
+            "llvm.x86.avx512.mask.pmovs.dw.128" => "__builtin_ia32_pmovsdw128_mask",
+            "llvm.x86.avx512.mask.pmovs.dw.256" => "__builtin_ia32_pmovsdw256_mask",
+            "llvm.x86.avx512.mask.pmovs.dw.512" => "__builtin_ia32_pmovsdw512_mask",
+            "llvm.x86.avx512.mask.pmovs.dw.mem.128" => "__builtin_ia32_pmovsdw128mem_mask",
+            "llvm.x86.avx512.mask.pmovs.dw.mem.256" => "__builtin_ia32_pmovsdw256mem_mask",
+            "llvm.x86.avx512.mask.pmovs.dw.mem.512" => "__builtin_ia32_pmovsdw512mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qb.128" => "__builtin_ia32_pmovsqb128_mask",
+            "llvm.x86.avx512.mask.pmovs.qb.256" => "__builtin_ia32_pmovsqb256_mask",
+            "llvm.x86.avx512.mask.pmovs.qb.512" => "__builtin_ia32_pmovsqb512_mask",
+            "llvm.x86.avx512.mask.pmovs.qb.mem.128" => "__builtin_ia32_pmovsqb128mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qb.mem.256" => "__builtin_ia32_pmovsqb256mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qb.mem.512" => "__builtin_ia32_pmovsqb512mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qd.128" => "__builtin_ia32_pmovsqd128_mask",
+            "llvm.x86.avx512.mask.pmovs.qd.256" => "__builtin_ia32_pmovsqd256_mask",
+            "llvm.x86.avx512.mask.pmovs.qd.512" => "__builtin_ia32_pmovsqd512_mask",
+            "llvm.x86.avx512.mask.pmovs.qd.mem.128" => "__builtin_ia32_pmovsqd128mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qd.mem.256" => "__builtin_ia32_pmovsqd256mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qd.mem.512" => "__builtin_ia32_pmovsqd512mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qw.128" => "__builtin_ia32_pmovsqw128_mask",
+            "llvm.x86.avx512.mask.pmovs.qw.256" => "__builtin_ia32_pmovsqw256_mask",
+            "llvm.x86.avx512.mask.pmovs.qw.512" => "__builtin_ia32_pmovsqw512_mask",
+            "llvm.x86.avx512.mask.pmovs.qw.mem.128" => "__builtin_ia32_pmovsqw128mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qw.mem.256" => "__builtin_ia32_pmovsqw256mem_mask",
+            "llvm.x86.avx512.mask.pmovs.qw.mem.512" => "__builtin_ia32_pmovsqw512mem_mask",
+            "llvm.x86.avx512.mask.pmovs.wb.128" => "__builtin_ia32_pmovswb128_mask",
+            "llvm.x86.avx512.mask.pmovs.wb.256" => "__builtin_ia32_pmovswb256_mask",
+            "llvm.x86.avx512.mask.pmovs.wb.512" => "__builtin_ia32_pmovswb512_mask",
+            "llvm.x86.avx512.mask.pmovs.wb.mem.128" => "__builtin_ia32_pmovswb128mem_mask",
+            "llvm.x86.avx512.mask.pmovs.wb.mem.256" => "__builtin_ia32_pmovswb256mem_mask",
+            "llvm.x86.avx512.mask.pmovs.wb.mem.512" => "__builtin_ia32_pmovswb512mem_mask",
+            "llvm.x86.avx512.mask.pmovus.db.128" => "__builtin_ia32_pmovusdb128_mask",
+            "llvm.x86.avx512.mask.pmovus.db.256" => "__builtin_ia32_pmovusdb256_mask",
+            "llvm.x86.avx512.mask.pmovus.db.512" => "__builtin_ia32_pmovusdb512_mask",
+            "llvm.x86.avx512.mask.pmovus.db.mem.128" => "__builtin_ia32_pmovusdb128mem_mask",
+            "llvm.x86.avx512.mask.pmovus.db.mem.256" => "__builtin_ia32_pmovusdb256mem_mask",
+            "llvm.x86.avx512.mask.pmovus.db.mem.512" => "__builtin_ia32_pmovusdb512mem_mask",
+            "llvm.x86.avx512.mask.pmovus.dw.128" => "__builtin_ia32_pmovusdw128_mask",
+            "llvm.x86.avx512.mask.pmovus.dw.256" => "__builtin_ia32_pmovusdw256_mask",
+            "llvm.x86.avx512.mask.pmovus.dw.512" => "__builtin_ia32_pmovusdw512_mask",
+            "llvm.x86.avx512.mask.pmovus.dw.mem.128" => "__builtin_ia32_pmovusdw128mem_mask",
+            "llvm.x86.avx512.mask.pmovus.dw.mem.256" => "__builtin_ia32_pmovusdw256mem_mask",
+            "llvm.x86.avx512.mask.pmovus.dw.mem.512" => "__builtin_ia32_pmovusdw512mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qb.128" => "__builtin_ia32_pmovusqb128_mask",
+            "llvm.x86.avx512.mask.pmovus.qb.256" => "__builtin_ia32_pmovusqb256_mask",
+            "llvm.x86.avx512.mask.pmovus.qb.512" => "__builtin_ia32_pmovusqb512_mask",
+            "llvm.x86.avx512.mask.pmovus.qb.mem.128" => "__builtin_ia32_pmovusqb128mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qb.mem.256" => "__builtin_ia32_pmovusqb256mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qb.mem.512" => "__builtin_ia32_pmovusqb512mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qd.128" => "__builtin_ia32_pmovusqd128_mask",
+            "llvm.x86.avx512.mask.pmovus.qd.256" => "__builtin_ia32_pmovusqd256_mask",
+            "llvm.x86.avx512.mask.pmovus.qd.512" => "__builtin_ia32_pmovusqd512_mask",
+            "llvm.x86.avx512.mask.pmovus.qd.mem.128" => "__builtin_ia32_pmovusqd128mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qd.mem.256" => "__builtin_ia32_pmovusqd256mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qd.mem.512" => "__builtin_ia32_pmovusqd512mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qw.128" => "__builtin_ia32_pmovusqw128_mask",
+            "llvm.x86.avx512.mask.pmovus.qw.256" => "__builtin_ia32_pmovusqw256_mask",
+            "llvm.x86.avx512.mask.pmovus.qw.512" => "__builtin_ia32_pmovusqw512_mask",
+            "llvm.x86.avx512.mask.pmovus.qw.mem.128" => "__builtin_ia32_pmovusqw128mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qw.mem.256" => "__builtin_ia32_pmovusqw256mem_mask",
+            "llvm.x86.avx512.mask.pmovus.qw.mem.512" => "__builtin_ia32_pmovusqw512mem_mask",
+            "llvm.x86.avx512.mask.pmovus.wb.128" => "__builtin_ia32_pmovuswb128_mask",
+            "llvm.x86.avx512.mask.pmovus.wb.256" => "__builtin_ia32_pmovuswb256_mask",
+            "llvm.x86.avx512.mask.pmovus.wb.512" => "__builtin_ia32_pmovuswb512_mask",
+            "llvm.x86.avx512.mask.pmovus.wb.mem.128" => "__builtin_ia32_pmovuswb128mem_mask",
+            "llvm.x86.avx512.mask.pmovus.wb.mem.256" => "__builtin_ia32_pmovuswb256mem_mask",
+            "llvm.x86.avx512.mask.pmovus.wb.mem.512" => "__builtin_ia32_pmovuswb512mem_mask",
+            "llvm.x86.avx512.mask.range.pd.128" => "__builtin_ia32_rangepd128_mask",
+            "llvm.x86.avx512.mask.range.pd.256" => "__builtin_ia32_rangepd256_mask",
+            "llvm.x86.avx512.mask.range.pd.512" => "__builtin_ia32_rangepd512_mask",
+            "llvm.x86.avx512.mask.range.ps.128" => "__builtin_ia32_rangeps128_mask",
+            "llvm.x86.avx512.mask.range.ps.256" => "__builtin_ia32_rangeps256_mask",
+            "llvm.x86.avx512.mask.range.ps.512" => "__builtin_ia32_rangeps512_mask",
+            "llvm.x86.avx512.mask.range.sd" => "__builtin_ia32_rangesd128_mask_round",
+            "llvm.x86.avx512.mask.range.ss" => "__builtin_ia32_rangess128_mask_round",
+            "llvm.x86.avx512.mask.reduce.pd.128" => "__builtin_ia32_reducepd128_mask",
+            "llvm.x86.avx512.mask.reduce.pd.256" => "__builtin_ia32_reducepd256_mask",
+            "llvm.x86.avx512.mask.reduce.pd.512" => "__builtin_ia32_reducepd512_mask",
+            "llvm.x86.avx512.mask.reduce.ps.128" => "__builtin_ia32_reduceps128_mask",
+            "llvm.x86.avx512.mask.reduce.ps.256" => "__builtin_ia32_reduceps256_mask",
+            "llvm.x86.avx512.mask.reduce.ps.512" => "__builtin_ia32_reduceps512_mask",
+            "llvm.x86.avx512.mask.reduce.sd" => "__builtin_ia32_reducesd_mask",
+            "llvm.x86.avx512.mask.reduce.ss" => "__builtin_ia32_reducess_mask",
+            "llvm.x86.avx512.mask.rndscale.pd.128" => "__builtin_ia32_rndscalepd_128_mask",
+            "llvm.x86.avx512.mask.rndscale.pd.256" => "__builtin_ia32_rndscalepd_256_mask",
+            "llvm.x86.avx512.mask.rndscale.pd.512" => "__builtin_ia32_rndscalepd_mask",
+            "llvm.x86.avx512.mask.rndscale.ps.128" => "__builtin_ia32_rndscaleps_128_mask",
+            "llvm.x86.avx512.mask.rndscale.ps.256" => "__builtin_ia32_rndscaleps_256_mask",
+            "llvm.x86.avx512.mask.rndscale.ps.512" => "__builtin_ia32_rndscaleps_mask",
+            "llvm.x86.avx512.mask.rndscale.sd" => "__builtin_ia32_rndscalesd_mask_round",
+            "llvm.x86.avx512.mask.rndscale.ss" => "__builtin_ia32_rndscaless_mask_round",
+            "llvm.x86.avx512.mask.scalef.pd.128" => "__builtin_ia32_scalefpd128_mask",
+            "llvm.x86.avx512.mask.scalef.pd.256" => "__builtin_ia32_scalefpd256_mask",
+            "llvm.x86.avx512.mask.scalef.pd.512" => "__builtin_ia32_scalefpd512_mask",
+            "llvm.x86.avx512.mask.scalef.ps.128" => "__builtin_ia32_scalefps128_mask",
+            "llvm.x86.avx512.mask.scalef.ps.256" => "__builtin_ia32_scalefps256_mask",
+            "llvm.x86.avx512.mask.scalef.ps.512" => "__builtin_ia32_scalefps512_mask",
+            "llvm.x86.avx512.mask.scalef.sd" => "__builtin_ia32_scalefsd_mask_round",
+            "llvm.x86.avx512.mask.scalef.ss" => "__builtin_ia32_scalefss_mask_round",
+            "llvm.x86.avx512.mask.sub.sd.round" => "__builtin_ia32_subsd_mask_round",
+            "llvm.x86.avx512.mask.sub.ss.round" => "__builtin_ia32_subss_mask_round",
+            "llvm.x86.avx512.mask.vcvtps2ph.128" => "__builtin_ia32_vcvtps2ph_mask",
+            "llvm.x86.avx512.mask.vcvtps2ph.256" => "__builtin_ia32_vcvtps2ph256_mask",
+            "llvm.x86.avx512.mask.vcvtps2ph.512" => "__builtin_ia32_vcvtps2ph512_mask",
+            "llvm.x86.avx512.maskz.fixupimm.pd.128" => "__builtin_ia32_fixupimmpd128_maskz",
+            "llvm.x86.avx512.maskz.fixupimm.pd.256" => "__builtin_ia32_fixupimmpd256_maskz",
+            "llvm.x86.avx512.maskz.fixupimm.pd.512" => "__builtin_ia32_fixupimmpd512_maskz",
+            "llvm.x86.avx512.maskz.fixupimm.ps.128" => "__builtin_ia32_fixupimmps128_maskz",
+            "llvm.x86.avx512.maskz.fixupimm.ps.256" => "__builtin_ia32_fixupimmps256_maskz",
+            "llvm.x86.avx512.maskz.fixupimm.ps.512" => "__builtin_ia32_fixupimmps512_maskz",
+            "llvm.x86.avx512.maskz.fixupimm.sd" => "__builtin_ia32_fixupimmsd_maskz",
+            "llvm.x86.avx512.maskz.fixupimm.ss" => "__builtin_ia32_fixupimmss_maskz",
+            "llvm.x86.avx512.max.pd.512" => "__builtin_ia32_maxpd512_mask",
+            "llvm.x86.avx512.max.ps.512" => "__builtin_ia32_maxps512_mask",
+            "llvm.x86.avx512.min.pd.512" => "__builtin_ia32_minpd512_mask",
+            "llvm.x86.avx512.min.ps.512" => "__builtin_ia32_minps512_mask",
+            "llvm.x86.avx512.mul.pd.512" => "__builtin_ia32_mulpd512_mask",
+            "llvm.x86.avx512.mul.ps.512" => "__builtin_ia32_mulps512_mask",
+            "llvm.x86.avx512.packssdw.512" => "__builtin_ia32_packssdw512_mask",
+            "llvm.x86.avx512.packsswb.512" => "__builtin_ia32_packsswb512_mask",
+            "llvm.x86.avx512.packusdw.512" => "__builtin_ia32_packusdw512_mask",
+            "llvm.x86.avx512.packuswb.512" => "__builtin_ia32_packuswb512_mask",
+            "llvm.x86.avx512.pavg.b.512" => "__builtin_ia32_pavgb512_mask",
+            "llvm.x86.avx512.pavg.w.512" => "__builtin_ia32_pavgw512_mask",
+            "llvm.x86.avx512.permvar.df.256" => "__builtin_ia32_permvardf256_mask",
+            "llvm.x86.avx512.permvar.df.512" => "__builtin_ia32_permvardf512_mask",
+            "llvm.x86.avx512.permvar.di.256" => "__builtin_ia32_permvardi256_mask",
+            "llvm.x86.avx512.permvar.di.512" => "__builtin_ia32_permvardi512_mask",
+            "llvm.x86.avx512.permvar.hi.128" => "__builtin_ia32_permvarhi128_mask",
+            "llvm.x86.avx512.permvar.hi.256" => "__builtin_ia32_permvarhi256_mask",
+            "llvm.x86.avx512.permvar.hi.512" => "__builtin_ia32_permvarhi512_mask",
+            "llvm.x86.avx512.permvar.qi.128" => "__builtin_ia32_permvarqi128_mask",
+            "llvm.x86.avx512.permvar.qi.256" => "__builtin_ia32_permvarqi256_mask",
+            "llvm.x86.avx512.permvar.qi.512" => "__builtin_ia32_permvarqi512_mask",
+            "llvm.x86.avx512.permvar.sf.512" => "__builtin_ia32_permvarsf512_mask",
+            "llvm.x86.avx512.permvar.si.512" => "__builtin_ia32_permvarsi512_mask",
+            "llvm.x86.avx512.pmaddubs.w.512" => "__builtin_ia32_pmaddubsw512_mask",
+            "llvm.x86.avx512.pmaddw.d.512" => "__builtin_ia32_pmaddwd512_mask",
+            "llvm.x86.avx512.pmul.hr.sw.512" => "__builtin_ia32_pmulhrsw512_mask",
+            "llvm.x86.avx512.pmulh.w.512" => "__builtin_ia32_pmulhw512_mask",
+            "llvm.x86.avx512.pmulhu.w.512" => "__builtin_ia32_pmulhuw512_mask",
+            "llvm.x86.avx512.pmultishift.qb.128" => "__builtin_ia32_vpmultishiftqb128_mask",
+            "llvm.x86.avx512.pmultishift.qb.256" => "__builtin_ia32_vpmultishiftqb256_mask",
+            "llvm.x86.avx512.pmultishift.qb.512" => "__builtin_ia32_vpmultishiftqb512_mask",
+            "llvm.x86.avx512.psad.bw.512" => "__builtin_ia32_psadbw512",
+            "llvm.x86.avx512.pshuf.b.512" => "__builtin_ia32_pshufb512_mask",
+            "llvm.x86.avx512.psll.d.512" => "__builtin_ia32_pslld512_mask",
+            "llvm.x86.avx512.psll.q.512" => "__builtin_ia32_psllq512_mask",
+            "llvm.x86.avx512.psll.w.512" => "__builtin_ia32_psllw512_mask",
+            "llvm.x86.avx512.pslli.d.512" => "__builtin_ia32_pslldi512_mask",
+            "llvm.x86.avx512.pslli.q.512" => "__builtin_ia32_psllqi512_mask",
+            "llvm.x86.avx512.pslli.w.512" => "__builtin_ia32_psllwi512_mask",
+            "llvm.x86.avx512.psllv.d.512" => "__builtin_ia32_psllv16si_mask",
+            "llvm.x86.avx512.psllv.q.512" => "__builtin_ia32_psllv8di_mask",
+            "llvm.x86.avx512.psllv.w.128" => "__builtin_ia32_psllv8hi_mask",
+            "llvm.x86.avx512.psllv.w.256" => "__builtin_ia32_psllv16hi_mask",
+            "llvm.x86.avx512.psllv.w.512" => "__builtin_ia32_psllv32hi_mask",
+            "llvm.x86.avx512.psra.d.512" => "__builtin_ia32_psrad512_mask",
+            "llvm.x86.avx512.psra.q.128" => "__builtin_ia32_psraq128_mask",
+            "llvm.x86.avx512.psra.q.256" => "__builtin_ia32_psraq256_mask",
+            "llvm.x86.avx512.psra.q.512" => "__builtin_ia32_psraq512_mask",
+            "llvm.x86.avx512.psra.w.512" => "__builtin_ia32_psraw512_mask",
+            "llvm.x86.avx512.psrai.d.512" => "__builtin_ia32_psradi512_mask",
+            "llvm.x86.avx512.psrai.q.128" => "__builtin_ia32_psraqi128_mask",
+            "llvm.x86.avx512.psrai.q.256" => "__builtin_ia32_psraqi256_mask",
+            "llvm.x86.avx512.psrai.q.512" => "__builtin_ia32_psraqi512_mask",
+            "llvm.x86.avx512.psrai.w.512" => "__builtin_ia32_psrawi512_mask",
+            "llvm.x86.avx512.psrav.d.512" => "__builtin_ia32_psrav16si_mask",
+            "llvm.x86.avx512.psrav.q.128" => "__builtin_ia32_psravq128_mask",
+            "llvm.x86.avx512.psrav.q.256" => "__builtin_ia32_psravq256_mask",
+            "llvm.x86.avx512.psrav.q.512" => "__builtin_ia32_psrav8di_mask",
+            "llvm.x86.avx512.psrav.w.128" => "__builtin_ia32_psrav8hi_mask",
+            "llvm.x86.avx512.psrav.w.256" => "__builtin_ia32_psrav16hi_mask",
+            "llvm.x86.avx512.psrav.w.512" => "__builtin_ia32_psrav32hi_mask",
+            "llvm.x86.avx512.psrl.d.512" => "__builtin_ia32_psrld512_mask",
+            "llvm.x86.avx512.psrl.q.512" => "__builtin_ia32_psrlq512_mask",
+            "llvm.x86.avx512.psrl.w.512" => "__builtin_ia32_psrlw512_mask",
+            "llvm.x86.avx512.psrli.d.512" => "__builtin_ia32_psrldi512_mask",
+            "llvm.x86.avx512.psrli.q.512" => "__builtin_ia32_psrlqi512_mask",
+            "llvm.x86.avx512.psrli.w.512" => "__builtin_ia32_psrlwi512_mask",
+            "llvm.x86.avx512.psrlv.d.512" => "__builtin_ia32_psrlv16si_mask",
+            "llvm.x86.avx512.psrlv.q.512" => "__builtin_ia32_psrlv8di_mask",
+            "llvm.x86.avx512.psrlv.w.128" => "__builtin_ia32_psrlv8hi_mask",
+            "llvm.x86.avx512.psrlv.w.256" => "__builtin_ia32_psrlv16hi_mask",
+            "llvm.x86.avx512.psrlv.w.512" => "__builtin_ia32_psrlv32hi_mask",
+            "llvm.x86.avx512.pternlog.d.128" => "__builtin_ia32_pternlogd128_mask",
+            "llvm.x86.avx512.pternlog.d.256" => "__builtin_ia32_pternlogd256_mask",
+            "llvm.x86.avx512.pternlog.d.512" => "__builtin_ia32_pternlogd512_mask",
+            "llvm.x86.avx512.pternlog.q.128" => "__builtin_ia32_pternlogq128_mask",
+            "llvm.x86.avx512.pternlog.q.256" => "__builtin_ia32_pternlogq256_mask",
+            "llvm.x86.avx512.pternlog.q.512" => "__builtin_ia32_pternlogq512_mask",
+            "llvm.x86.avx512.rcp14.pd.128" => "__builtin_ia32_rcp14pd128_mask",
+            "llvm.x86.avx512.rcp14.pd.256" => "__builtin_ia32_rcp14pd256_mask",
+            "llvm.x86.avx512.rcp14.pd.512" => "__builtin_ia32_rcp14pd512_mask",
+            "llvm.x86.avx512.rcp14.ps.128" => "__builtin_ia32_rcp14ps128_mask",
+            "llvm.x86.avx512.rcp14.ps.256" => "__builtin_ia32_rcp14ps256_mask",
+            "llvm.x86.avx512.rcp14.ps.512" => "__builtin_ia32_rcp14ps512_mask",
+            "llvm.x86.avx512.rcp14.sd" => "__builtin_ia32_rcp14sd_mask",
+            "llvm.x86.avx512.rcp14.ss" => "__builtin_ia32_rcp14ss_mask",
+            "llvm.x86.avx512.rcp28.pd" => "__builtin_ia32_rcp28pd_mask",
+            "llvm.x86.avx512.rcp28.ps" => "__builtin_ia32_rcp28ps_mask",
+            "llvm.x86.avx512.rcp28.sd" => "__builtin_ia32_rcp28sd_mask_round",
+            "llvm.x86.avx512.rcp28.ss" => "__builtin_ia32_rcp28ss_mask_round",
+            "llvm.x86.avx512.rsqrt14.pd.128" => "__builtin_ia32_rsqrt14pd128_mask",
+            "llvm.x86.avx512.rsqrt14.pd.256" => "__builtin_ia32_rsqrt14pd256_mask",
+            "llvm.x86.avx512.rsqrt14.pd.512" => "__builtin_ia32_rsqrt14pd512_mask",
+            "llvm.x86.avx512.rsqrt14.ps.128" => "__builtin_ia32_rsqrt14ps128_mask",
+            "llvm.x86.avx512.rsqrt14.ps.256" => "__builtin_ia32_rsqrt14ps256_mask",
+            "llvm.x86.avx512.rsqrt14.ps.512" => "__builtin_ia32_rsqrt14ps512_mask",
+            "llvm.x86.avx512.rsqrt14.sd" => "__builtin_ia32_rsqrt14sd_mask",
+            "llvm.x86.avx512.rsqrt14.ss" => "__builtin_ia32_rsqrt14ss_mask",
+            "llvm.x86.avx512.rsqrt28.pd" => "__builtin_ia32_rsqrt28pd_mask",
+            "llvm.x86.avx512.rsqrt28.ps" => "__builtin_ia32_rsqrt28ps_mask",
+            "llvm.x86.avx512.rsqrt28.sd" => "__builtin_ia32_rsqrt28sd_mask_round",
+            "llvm.x86.avx512.rsqrt28.ss" => "__builtin_ia32_rsqrt28ss_mask_round",
+            "llvm.x86.avx512.scatterpf.dpd.512" => "__builtin_ia32_scatterpfdpd",
+            "llvm.x86.avx512.scatterpf.dps.512" => "__builtin_ia32_scatterpfdps",
+            "llvm.x86.avx512.scatterpf.qpd.512" => "__builtin_ia32_scatterpfqpd",
+            "llvm.x86.avx512.scatterpf.qps.512" => "__builtin_ia32_scatterpfqps",
+            "llvm.x86.avx512.sub.pd.512" => "__builtin_ia32_subpd512_mask",
+            "llvm.x86.avx512.sub.ps.512" => "__builtin_ia32_subps512_mask",
+            "llvm.x86.avx512.vcomi.sd" => "__builtin_ia32_vcomisd",
+            "llvm.x86.avx512.vcomi.ss" => "__builtin_ia32_vcomiss",
+            "llvm.x86.avx512.vcvtsd2si32" => "__builtin_ia32_vcvtsd2si32",
+            "llvm.x86.avx512.vcvtsd2si64" => "__builtin_ia32_vcvtsd2si64",
+            "llvm.x86.avx512.vcvtsd2usi32" => "__builtin_ia32_vcvtsd2usi32",
+            "llvm.x86.avx512.vcvtsd2usi64" => "__builtin_ia32_vcvtsd2usi64",
+            "llvm.x86.avx512.vcvtss2si32" => "__builtin_ia32_vcvtss2si32",
+            "llvm.x86.avx512.vcvtss2si64" => "__builtin_ia32_vcvtss2si64",
+            "llvm.x86.avx512.vcvtss2usi32" => "__builtin_ia32_vcvtss2usi32",
+            "llvm.x86.avx512.vcvtss2usi64" => "__builtin_ia32_vcvtss2usi64",
+            "llvm.x86.avx512.vpermi2var.d.128" => "__builtin_ia32_vpermi2vard128_mask",
+            "llvm.x86.avx512.vpermi2var.d.256" => "__builtin_ia32_vpermi2vard256_mask",
+            "llvm.x86.avx512.vpermi2var.d.512" => "__builtin_ia32_vpermi2vard512_mask",
+            "llvm.x86.avx512.vpermi2var.hi.128" => "__builtin_ia32_vpermi2varhi128_mask",
+            "llvm.x86.avx512.vpermi2var.hi.256" => "__builtin_ia32_vpermi2varhi256_mask",
+            "llvm.x86.avx512.vpermi2var.hi.512" => "__builtin_ia32_vpermi2varhi512_mask",
+            "llvm.x86.avx512.vpermi2var.pd.128" => "__builtin_ia32_vpermi2varpd128_mask",
+            "llvm.x86.avx512.vpermi2var.pd.256" => "__builtin_ia32_vpermi2varpd256_mask",
+            "llvm.x86.avx512.vpermi2var.pd.512" => "__builtin_ia32_vpermi2varpd512_mask",
+            "llvm.x86.avx512.vpermi2var.ps.128" => "__builtin_ia32_vpermi2varps128_mask",
+            "llvm.x86.avx512.vpermi2var.ps.256" => "__builtin_ia32_vpermi2varps256_mask",
+            "llvm.x86.avx512.vpermi2var.ps.512" => "__builtin_ia32_vpermi2varps512_mask",
+            "llvm.x86.avx512.vpermi2var.q.128" => "__builtin_ia32_vpermi2varq128_mask",
+            "llvm.x86.avx512.vpermi2var.q.256" => "__builtin_ia32_vpermi2varq256_mask",
+            "llvm.x86.avx512.vpermi2var.q.512" => "__builtin_ia32_vpermi2varq512_mask",
+            "llvm.x86.avx512.vpermi2var.qi.128" => "__builtin_ia32_vpermi2varqi128_mask",
+            "llvm.x86.avx512.vpermi2var.qi.256" => "__builtin_ia32_vpermi2varqi256_mask",
+            "llvm.x86.avx512.vpermi2var.qi.512" => "__builtin_ia32_vpermi2varqi512_mask",
+            "llvm.x86.avx512.vpermilvar.pd.512" => "__builtin_ia32_vpermilvarpd512_mask",
+            "llvm.x86.avx512.vpermilvar.ps.512" => "__builtin_ia32_vpermilvarps512_mask",
+            "llvm.x86.avx512.vpmadd52h.uq.128" => "__builtin_ia32_vpmadd52huq128_mask",
+            "llvm.x86.avx512.vpmadd52h.uq.256" => "__builtin_ia32_vpmadd52huq256_mask",
+            "llvm.x86.avx512.vpmadd52h.uq.512" => "__builtin_ia32_vpmadd52huq512_mask",
+            "llvm.x86.avx512.vpmadd52l.uq.128" => "__builtin_ia32_vpmadd52luq128_mask",
+            "llvm.x86.avx512.vpmadd52l.uq.256" => "__builtin_ia32_vpmadd52luq256_mask",
+            "llvm.x86.avx512.vpmadd52l.uq.512" => "__builtin_ia32_vpmadd52luq512_mask",
+            "llvm.x86.bmi.bextr.32" => "__builtin_ia32_bextr_u32",
+            "llvm.x86.bmi.bextr.64" => "__builtin_ia32_bextr_u64",
+            "llvm.x86.bmi.bzhi.32" => "__builtin_ia32_bzhi_si",
+            "llvm.x86.bmi.bzhi.64" => "__builtin_ia32_bzhi_di",
+            "llvm.x86.bmi.pdep.32" => "__builtin_ia32_pdep_si",
+            "llvm.x86.bmi.pdep.64" => "__builtin_ia32_pdep_di",
+            "llvm.x86.bmi.pext.32" => "__builtin_ia32_pext_si",
+            "llvm.x86.bmi.pext.64" => "__builtin_ia32_pext_di",
+            "llvm.x86.cldemote" => "__builtin_ia32_cldemote",
+            "llvm.x86.clflushopt" => "__builtin_ia32_clflushopt",
+            "llvm.x86.clrssbsy" => "__builtin_ia32_clrssbsy",
+            "llvm.x86.clui" => "__builtin_ia32_clui",
+            "llvm.x86.clwb" => "__builtin_ia32_clwb",
+            "llvm.x86.clzero" => "__builtin_ia32_clzero",
+            "llvm.x86.enqcmd" => "__builtin_ia32_enqcmd",
+            "llvm.x86.enqcmds" => "__builtin_ia32_enqcmds",
+            "llvm.x86.flags.read.u32" => "__builtin_ia32_readeflags_u32",
+            "llvm.x86.flags.read.u64" => "__builtin_ia32_readeflags_u64",
+            "llvm.x86.flags.write.u32" => "__builtin_ia32_writeeflags_u32",
+            "llvm.x86.flags.write.u64" => "__builtin_ia32_writeeflags_u64",
+            "llvm.x86.fma.vfmaddsub.pd" => "__builtin_ia32_vfmaddsubpd",
+            "llvm.x86.fma.vfmaddsub.pd.256" => "__builtin_ia32_vfmaddsubpd256",
+            "llvm.x86.fma.vfmaddsub.ps" => "__builtin_ia32_vfmaddsubps",
+            "llvm.x86.fma.vfmaddsub.ps.256" => "__builtin_ia32_vfmaddsubps256",
+            "llvm.x86.fxrstor" => "__builtin_ia32_fxrstor",
+            "llvm.x86.fxrstor64" => "__builtin_ia32_fxrstor64",
+            "llvm.x86.fxsave" => "__builtin_ia32_fxsave",
+            "llvm.x86.fxsave64" => "__builtin_ia32_fxsave64",
+            "llvm.x86.incsspd" => "__builtin_ia32_incsspd",
+            "llvm.x86.incsspq" => "__builtin_ia32_incsspq",
+            "llvm.x86.llwpcb" => "__builtin_ia32_llwpcb",
+            "llvm.x86.loadiwkey" => "__builtin_ia32_loadiwkey",
+            "llvm.x86.lwpins32" => "__builtin_ia32_lwpins32",
+            "llvm.x86.lwpins64" => "__builtin_ia32_lwpins64",
+            "llvm.x86.lwpval32" => "__builtin_ia32_lwpval32",
+            "llvm.x86.lwpval64" => "__builtin_ia32_lwpval64",
+            "llvm.x86.mmx.emms" => "__builtin_ia32_emms",
+            "llvm.x86.mmx.maskmovq" => "__builtin_ia32_maskmovq",
+            "llvm.x86.mmx.movnt.dq" => "__builtin_ia32_movntq",
+            "llvm.x86.mmx.packssdw" => "__builtin_ia32_packssdw",
+            "llvm.x86.mmx.packsswb" => "__builtin_ia32_packsswb",
+            "llvm.x86.mmx.packuswb" => "__builtin_ia32_packuswb",
+            "llvm.x86.mmx.padd.b" => "__builtin_ia32_paddb",
+            "llvm.x86.mmx.padd.d" => "__builtin_ia32_paddd",
+            "llvm.x86.mmx.padd.q" => "__builtin_ia32_paddq",
+            "llvm.x86.mmx.padd.w" => "__builtin_ia32_paddw",
+            "llvm.x86.mmx.padds.b" => "__builtin_ia32_paddsb",
+            "llvm.x86.mmx.padds.w" => "__builtin_ia32_paddsw",
+            "llvm.x86.mmx.paddus.b" => "__builtin_ia32_paddusb",
+            "llvm.x86.mmx.paddus.w" => "__builtin_ia32_paddusw",
+            "llvm.x86.mmx.palignr.b" => "__builtin_ia32_palignr",
+            "llvm.x86.mmx.pand" => "__builtin_ia32_pand",
+            "llvm.x86.mmx.pandn" => "__builtin_ia32_pandn",
+            "llvm.x86.mmx.pavg.b" => "__builtin_ia32_pavgb",
+            "llvm.x86.mmx.pavg.w" => "__builtin_ia32_pavgw",
+            "llvm.x86.mmx.pcmpeq.b" => "__builtin_ia32_pcmpeqb",
+            "llvm.x86.mmx.pcmpeq.d" => "__builtin_ia32_pcmpeqd",
+            "llvm.x86.mmx.pcmpeq.w" => "__builtin_ia32_pcmpeqw",
+            "llvm.x86.mmx.pcmpgt.b" => "__builtin_ia32_pcmpgtb",
+            "llvm.x86.mmx.pcmpgt.d" => "__builtin_ia32_pcmpgtd",
+            "llvm.x86.mmx.pcmpgt.w" => "__builtin_ia32_pcmpgtw",
+            "llvm.x86.mmx.pextr.w" => "__builtin_ia32_vec_ext_v4hi",
+            "llvm.x86.mmx.pinsr.w" => "__builtin_ia32_vec_set_v4hi",
+            "llvm.x86.mmx.pmadd.wd" => "__builtin_ia32_pmaddwd",
+            "llvm.x86.mmx.pmaxs.w" => "__builtin_ia32_pmaxsw",
+            "llvm.x86.mmx.pmaxu.b" => "__builtin_ia32_pmaxub",
+            "llvm.x86.mmx.pmins.w" => "__builtin_ia32_pminsw",
+            "llvm.x86.mmx.pminu.b" => "__builtin_ia32_pminub",
+            "llvm.x86.mmx.pmovmskb" => "__builtin_ia32_pmovmskb",
+            "llvm.x86.mmx.pmulh.w" => "__builtin_ia32_pmulhw",
+            "llvm.x86.mmx.pmulhu.w" => "__builtin_ia32_pmulhuw",
+            "llvm.x86.mmx.pmull.w" => "__builtin_ia32_pmullw",
+            "llvm.x86.mmx.pmulu.dq" => "__builtin_ia32_pmuludq",
+            "llvm.x86.mmx.por" => "__builtin_ia32_por",
+            "llvm.x86.mmx.psad.bw" => "__builtin_ia32_psadbw",
+            "llvm.x86.mmx.psll.d" => "__builtin_ia32_pslld",
+            "llvm.x86.mmx.psll.q" => "__builtin_ia32_psllq",
+            "llvm.x86.mmx.psll.w" => "__builtin_ia32_psllw",
+            "llvm.x86.mmx.pslli.d" => "__builtin_ia32_pslldi",
+            "llvm.x86.mmx.pslli.q" => "__builtin_ia32_psllqi",
+            "llvm.x86.mmx.pslli.w" => "__builtin_ia32_psllwi",
+            "llvm.x86.mmx.psra.d" => "__builtin_ia32_psrad",
+            "llvm.x86.mmx.psra.w" => "__builtin_ia32_psraw",
+            "llvm.x86.mmx.psrai.d" => "__builtin_ia32_psradi",
+            "llvm.x86.mmx.psrai.w" => "__builtin_ia32_psrawi",
+            "llvm.x86.mmx.psrl.d" => "__builtin_ia32_psrld",
+            "llvm.x86.mmx.psrl.q" => "__builtin_ia32_psrlq",
+            "llvm.x86.mmx.psrl.w" => "__builtin_ia32_psrlw",
+            "llvm.x86.mmx.psrli.d" => "__builtin_ia32_psrldi",
+            "llvm.x86.mmx.psrli.q" => "__builtin_ia32_psrlqi",
+            "llvm.x86.mmx.psrli.w" => "__builtin_ia32_psrlwi",
+            "llvm.x86.mmx.psub.b" => "__builtin_ia32_psubb",
+            "llvm.x86.mmx.psub.d" => "__builtin_ia32_psubd",
+            "llvm.x86.mmx.psub.q" => "__builtin_ia32_psubq",
+            "llvm.x86.mmx.psub.w" => "__builtin_ia32_psubw",
+            "llvm.x86.mmx.psubs.b" => "__builtin_ia32_psubsb",
+            "llvm.x86.mmx.psubs.w" => "__builtin_ia32_psubsw",
+            "llvm.x86.mmx.psubus.b" => "__builtin_ia32_psubusb",
+            "llvm.x86.mmx.psubus.w" => "__builtin_ia32_psubusw",
+            "llvm.x86.mmx.punpckhbw" => "__builtin_ia32_punpckhbw",
+            "llvm.x86.mmx.punpckhdq" => "__builtin_ia32_punpckhdq",
+            "llvm.x86.mmx.punpckhwd" => "__builtin_ia32_punpckhwd",
+            "llvm.x86.mmx.punpcklbw" => "__builtin_ia32_punpcklbw",
+            "llvm.x86.mmx.punpckldq" => "__builtin_ia32_punpckldq",
+            "llvm.x86.mmx.punpcklwd" => "__builtin_ia32_punpcklwd",
+            "llvm.x86.mmx.pxor" => "__builtin_ia32_pxor",
+            "llvm.x86.monitorx" => "__builtin_ia32_monitorx",
+            "llvm.x86.movdir64b" => "__builtin_ia32_movdir64b",
+            "llvm.x86.mwaitx" => "__builtin_ia32_mwaitx",
+            "llvm.x86.pclmulqdq" => "__builtin_ia32_pclmulqdq128",
+            "llvm.x86.ptwrite32" => "__builtin_ia32_ptwrite32",
+            "llvm.x86.ptwrite64" => "__builtin_ia32_ptwrite64",
+            "llvm.x86.rdfsbase.32" => "__builtin_ia32_rdfsbase32",
+            "llvm.x86.rdfsbase.64" => "__builtin_ia32_rdfsbase64",
+            "llvm.x86.rdgsbase.32" => "__builtin_ia32_rdgsbase32",
+            "llvm.x86.rdgsbase.64" => "__builtin_ia32_rdgsbase64",
+            "llvm.x86.rdpid" => "__builtin_ia32_rdpid",
+            "llvm.x86.rdpkru" => "__builtin_ia32_rdpkru",
+            "llvm.x86.rdpmc" => "__builtin_ia32_rdpmc",
+            "llvm.x86.rdsspd" => "__builtin_ia32_rdsspd",
+            "llvm.x86.rdsspq" => "__builtin_ia32_rdsspq",
+            "llvm.x86.rdtsc" => "__builtin_ia32_rdtsc",
+            "llvm.x86.rstorssp" => "__builtin_ia32_rstorssp",
+            "llvm.x86.saveprevssp" => "__builtin_ia32_saveprevssp",
+            "llvm.x86.senduipi" => "__builtin_ia32_senduipi",
+            "llvm.x86.serialize" => "__builtin_ia32_serialize",
+            "llvm.x86.setssbsy" => "__builtin_ia32_setssbsy",
+            "llvm.x86.sha1msg1" => "__builtin_ia32_sha1msg1",
+            "llvm.x86.sha1msg2" => "__builtin_ia32_sha1msg2",
+            "llvm.x86.sha1nexte" => "__builtin_ia32_sha1nexte",
+            "llvm.x86.sha1rnds4" => "__builtin_ia32_sha1rnds4",
+            "llvm.x86.sha256msg1" => "__builtin_ia32_sha256msg1",
+            "llvm.x86.sha256msg2" => "__builtin_ia32_sha256msg2",
+            "llvm.x86.sha256rnds2" => "__builtin_ia32_sha256rnds2",
+            "llvm.x86.slwpcb" => "__builtin_ia32_slwpcb",
+            "llvm.x86.sse.cmp.ss" => "__builtin_ia32_cmpss",
+            "llvm.x86.sse.comieq.ss" => "__builtin_ia32_comieq",
+            "llvm.x86.sse.comige.ss" => "__builtin_ia32_comige",
+            "llvm.x86.sse.comigt.ss" => "__builtin_ia32_comigt",
+            "llvm.x86.sse.comile.ss" => "__builtin_ia32_comile",
+            "llvm.x86.sse.comilt.ss" => "__builtin_ia32_comilt",
+            "llvm.x86.sse.comineq.ss" => "__builtin_ia32_comineq",
+            "llvm.x86.sse.cvtpd2pi" => "__builtin_ia32_cvtpd2pi",
+            "llvm.x86.sse.cvtpi2pd" => "__builtin_ia32_cvtpi2pd",
+            "llvm.x86.sse.cvtpi2ps" => "__builtin_ia32_cvtpi2ps",
+            "llvm.x86.sse.cvtps2pi" => "__builtin_ia32_cvtps2pi",
+            "llvm.x86.sse.cvtss2si" => "__builtin_ia32_cvtss2si",
+            "llvm.x86.sse.cvtss2si64" => "__builtin_ia32_cvtss2si64",
+            "llvm.x86.sse.cvttpd2pi" => "__builtin_ia32_cvttpd2pi",
+            "llvm.x86.sse.cvttps2pi" => "__builtin_ia32_cvttps2pi",
+            "llvm.x86.sse.cvttss2si" => "__builtin_ia32_cvttss2si",
+            "llvm.x86.sse.cvttss2si64" => "__builtin_ia32_cvttss2si64",
+            "llvm.x86.sse.max.ps" => "__builtin_ia32_maxps",
+            "llvm.x86.sse.max.ss" => "__builtin_ia32_maxss",
+            "llvm.x86.sse.min.ps" => "__builtin_ia32_minps",
+            "llvm.x86.sse.min.ss" => "__builtin_ia32_minss",
+            "llvm.x86.sse.movmsk.ps" => "__builtin_ia32_movmskps",
+            "llvm.x86.sse.pshuf.w" => "__builtin_ia32_pshufw",
+            "llvm.x86.sse.rcp.ps" => "__builtin_ia32_rcpps",
+            "llvm.x86.sse.rcp.ss" => "__builtin_ia32_rcpss",
+            "llvm.x86.sse.rsqrt.ps" => "__builtin_ia32_rsqrtps",
+            "llvm.x86.sse.rsqrt.ss" => "__builtin_ia32_rsqrtss",
+            "llvm.x86.sse.sfence" => "__builtin_ia32_sfence",
+            "llvm.x86.sse.ucomieq.ss" => "__builtin_ia32_ucomieq",
+            "llvm.x86.sse.ucomige.ss" => "__builtin_ia32_ucomige",
+            "llvm.x86.sse.ucomigt.ss" => "__builtin_ia32_ucomigt",
+            "llvm.x86.sse.ucomile.ss" => "__builtin_ia32_ucomile",
+            "llvm.x86.sse.ucomilt.ss" => "__builtin_ia32_ucomilt",
+            "llvm.x86.sse.ucomineq.ss" => "__builtin_ia32_ucomineq",
+            "llvm.x86.sse2.clflush" => "__builtin_ia32_clflush",
+            "llvm.x86.sse2.cmp.sd" => "__builtin_ia32_cmpsd",
+            "llvm.x86.sse2.comieq.sd" => "__builtin_ia32_comisdeq",
+            "llvm.x86.sse2.comige.sd" => "__builtin_ia32_comisdge",
+            "llvm.x86.sse2.comigt.sd" => "__builtin_ia32_comisdgt",
+            "llvm.x86.sse2.comile.sd" => "__builtin_ia32_comisdle",
+            "llvm.x86.sse2.comilt.sd" => "__builtin_ia32_comisdlt",
+            "llvm.x86.sse2.comineq.sd" => "__builtin_ia32_comisdneq",
+            "llvm.x86.sse2.cvtpd2dq" => "__builtin_ia32_cvtpd2dq",
+            "llvm.x86.sse2.cvtpd2ps" => "__builtin_ia32_cvtpd2ps",
+            "llvm.x86.sse2.cvtps2dq" => "__builtin_ia32_cvtps2dq",
+            "llvm.x86.sse2.cvtsd2si" => "__builtin_ia32_cvtsd2si",
+            "llvm.x86.sse2.cvtsd2si64" => "__builtin_ia32_cvtsd2si64",
+            "llvm.x86.sse2.cvtsd2ss" => "__builtin_ia32_cvtsd2ss",
+            "llvm.x86.sse2.cvttpd2dq" => "__builtin_ia32_cvttpd2dq",
+            "llvm.x86.sse2.cvttps2dq" => "__builtin_ia32_cvttps2dq",
+            "llvm.x86.sse2.cvttsd2si" => "__builtin_ia32_cvttsd2si",
+            "llvm.x86.sse2.cvttsd2si64" => "__builtin_ia32_cvttsd2si64",
+            "llvm.x86.sse2.lfence" => "__builtin_ia32_lfence",
+            "llvm.x86.sse2.maskmov.dqu" => "__builtin_ia32_maskmovdqu",
+            "llvm.x86.sse2.max.pd" => "__builtin_ia32_maxpd",
+            "llvm.x86.sse2.max.sd" => "__builtin_ia32_maxsd",
+            "llvm.x86.sse2.mfence" => "__builtin_ia32_mfence",
+            "llvm.x86.sse2.min.pd" => "__builtin_ia32_minpd",
+            "llvm.x86.sse2.min.sd" => "__builtin_ia32_minsd",
+            "llvm.x86.sse2.movmsk.pd" => "__builtin_ia32_movmskpd",
+            "llvm.x86.sse2.packssdw.128" => "__builtin_ia32_packssdw128",
+            "llvm.x86.sse2.packsswb.128" => "__builtin_ia32_packsswb128",
+            "llvm.x86.sse2.packuswb.128" => "__builtin_ia32_packuswb128",
+            "llvm.x86.sse2.pause" => "__builtin_ia32_pause",
+            "llvm.x86.sse2.pavg.b" => "__builtin_ia32_pavgb128",
+            "llvm.x86.sse2.pavg.w" => "__builtin_ia32_pavgw128",
+            "llvm.x86.sse2.pmadd.wd" => "__builtin_ia32_pmaddwd128",
+            "llvm.x86.sse2.pmovmskb.128" => "__builtin_ia32_pmovmskb128",
+            "llvm.x86.sse2.pmulh.w" => "__builtin_ia32_pmulhw128",
+            "llvm.x86.sse2.pmulhu.w" => "__builtin_ia32_pmulhuw128",
+            "llvm.x86.sse2.psad.bw" => "__builtin_ia32_psadbw128",
+            "llvm.x86.sse2.psll.d" => "__builtin_ia32_pslld128",
+            "llvm.x86.sse2.psll.q" => "__builtin_ia32_psllq128",
+            "llvm.x86.sse2.psll.w" => "__builtin_ia32_psllw128",
+            "llvm.x86.sse2.pslli.d" => "__builtin_ia32_pslldi128",
+            "llvm.x86.sse2.pslli.q" => "__builtin_ia32_psllqi128",
+            "llvm.x86.sse2.pslli.w" => "__builtin_ia32_psllwi128",
+            "llvm.x86.sse2.psra.d" => "__builtin_ia32_psrad128",
+            "llvm.x86.sse2.psra.w" => "__builtin_ia32_psraw128",
+            "llvm.x86.sse2.psrai.d" => "__builtin_ia32_psradi128",
+            "llvm.x86.sse2.psrai.w" => "__builtin_ia32_psrawi128",
+            "llvm.x86.sse2.psrl.d" => "__builtin_ia32_psrld128",
+            "llvm.x86.sse2.psrl.q" => "__builtin_ia32_psrlq128",
+            "llvm.x86.sse2.psrl.w" => "__builtin_ia32_psrlw128",
+            "llvm.x86.sse2.psrli.d" => "__builtin_ia32_psrldi128",
+            "llvm.x86.sse2.psrli.q" => "__builtin_ia32_psrlqi128",
+            "llvm.x86.sse2.psrli.w" => "__builtin_ia32_psrlwi128",
+            "llvm.x86.sse2.ucomieq.sd" => "__builtin_ia32_ucomisdeq",
+            "llvm.x86.sse2.ucomige.sd" => "__builtin_ia32_ucomisdge",
+            "llvm.x86.sse2.ucomigt.sd" => "__builtin_ia32_ucomisdgt",
+            "llvm.x86.sse2.ucomile.sd" => "__builtin_ia32_ucomisdle",
+            "llvm.x86.sse2.ucomilt.sd" => "__builtin_ia32_ucomisdlt",
+            "llvm.x86.sse2.ucomineq.sd" => "__builtin_ia32_ucomisdneq",
+            "llvm.x86.sse3.addsub.pd" => "__builtin_ia32_addsubpd",
+            "llvm.x86.sse3.addsub.ps" => "__builtin_ia32_addsubps",
+            "llvm.x86.sse3.hadd.pd" => "__builtin_ia32_haddpd",
+            "llvm.x86.sse3.hadd.ps" => "__builtin_ia32_haddps",
+            "llvm.x86.sse3.hsub.pd" => "__builtin_ia32_hsubpd",
+            "llvm.x86.sse3.hsub.ps" => "__builtin_ia32_hsubps",
+            "llvm.x86.sse3.ldu.dq" => "__builtin_ia32_lddqu",
+            "llvm.x86.sse3.monitor" => "__builtin_ia32_monitor",
+            "llvm.x86.sse3.mwait" => "__builtin_ia32_mwait",
+            "llvm.x86.sse41.blendvpd" => "__builtin_ia32_blendvpd",
+            "llvm.x86.sse41.blendvps" => "__builtin_ia32_blendvps",
+            "llvm.x86.sse41.dppd" => "__builtin_ia32_dppd",
+            "llvm.x86.sse41.dpps" => "__builtin_ia32_dpps",
+            "llvm.x86.sse41.insertps" => "__builtin_ia32_insertps128",
+            "llvm.x86.sse41.mpsadbw" => "__builtin_ia32_mpsadbw128",
+            "llvm.x86.sse41.packusdw" => "__builtin_ia32_packusdw128",
+            "llvm.x86.sse41.pblendvb" => "__builtin_ia32_pblendvb128",
+            "llvm.x86.sse41.phminposuw" => "__builtin_ia32_phminposuw128",
+            "llvm.x86.sse41.ptestc" => "__builtin_ia32_ptestc128",
+            "llvm.x86.sse41.ptestnzc" => "__builtin_ia32_ptestnzc128",
+            "llvm.x86.sse41.ptestz" => "__builtin_ia32_ptestz128",
+            "llvm.x86.sse41.round.pd" => "__builtin_ia32_roundpd",
+            "llvm.x86.sse41.round.ps" => "__builtin_ia32_roundps",
+            "llvm.x86.sse41.round.sd" => "__builtin_ia32_roundsd",
+            "llvm.x86.sse41.round.ss" => "__builtin_ia32_roundss",
+            "llvm.x86.sse42.crc32.32.16" => "__builtin_ia32_crc32hi",
+            "llvm.x86.sse42.crc32.32.32" => "__builtin_ia32_crc32si",
+            "llvm.x86.sse42.crc32.32.8" => "__builtin_ia32_crc32qi",
+            "llvm.x86.sse42.crc32.64.64" => "__builtin_ia32_crc32di",
+            "llvm.x86.sse42.pcmpestri128" => "__builtin_ia32_pcmpestri128",
+            "llvm.x86.sse42.pcmpestria128" => "__builtin_ia32_pcmpestria128",
+            "llvm.x86.sse42.pcmpestric128" => "__builtin_ia32_pcmpestric128",
+            "llvm.x86.sse42.pcmpestrio128" => "__builtin_ia32_pcmpestrio128",
+            "llvm.x86.sse42.pcmpestris128" => "__builtin_ia32_pcmpestris128",
+            "llvm.x86.sse42.pcmpestriz128" => "__builtin_ia32_pcmpestriz128",
+            "llvm.x86.sse42.pcmpestrm128" => "__builtin_ia32_pcmpestrm128",
+            "llvm.x86.sse42.pcmpistri128" => "__builtin_ia32_pcmpistri128",
+            "llvm.x86.sse42.pcmpistria128" => "__builtin_ia32_pcmpistria128",
+            "llvm.x86.sse42.pcmpistric128" => "__builtin_ia32_pcmpistric128",
+            "llvm.x86.sse42.pcmpistrio128" => "__builtin_ia32_pcmpistrio128",
+            "llvm.x86.sse42.pcmpistris128" => "__builtin_ia32_pcmpistris128",
+            "llvm.x86.sse42.pcmpistriz128" => "__builtin_ia32_pcmpistriz128",
+            "llvm.x86.sse42.pcmpistrm128" => "__builtin_ia32_pcmpistrm128",
+            "llvm.x86.sse4a.extrq" => "__builtin_ia32_extrq",
+            "llvm.x86.sse4a.extrqi" => "__builtin_ia32_extrqi",
+            "llvm.x86.sse4a.insertq" => "__builtin_ia32_insertq",
+            "llvm.x86.sse4a.insertqi" => "__builtin_ia32_insertqi",
+            "llvm.x86.ssse3.pabs.b" => "__builtin_ia32_pabsb",
+            "llvm.x86.ssse3.pabs.d" => "__builtin_ia32_pabsd",
+            "llvm.x86.ssse3.pabs.w" => "__builtin_ia32_pabsw",
+            "llvm.x86.ssse3.phadd.d" => "__builtin_ia32_phaddd",
+            "llvm.x86.ssse3.phadd.d.128" => "__builtin_ia32_phaddd128",
+            "llvm.x86.ssse3.phadd.sw" => "__builtin_ia32_phaddsw",
+            "llvm.x86.ssse3.phadd.sw.128" => "__builtin_ia32_phaddsw128",
+            "llvm.x86.ssse3.phadd.w" => "__builtin_ia32_phaddw",
+            "llvm.x86.ssse3.phadd.w.128" => "__builtin_ia32_phaddw128",
+            "llvm.x86.ssse3.phsub.d" => "__builtin_ia32_phsubd",
+            "llvm.x86.ssse3.phsub.d.128" => "__builtin_ia32_phsubd128",
+            "llvm.x86.ssse3.phsub.sw" => "__builtin_ia32_phsubsw",
+            "llvm.x86.ssse3.phsub.sw.128" => "__builtin_ia32_phsubsw128",
+            "llvm.x86.ssse3.phsub.w" => "__builtin_ia32_phsubw",
+            "llvm.x86.ssse3.phsub.w.128" => "__builtin_ia32_phsubw128",
+            "llvm.x86.ssse3.pmadd.ub.sw" => "__builtin_ia32_pmaddubsw",
+            "llvm.x86.ssse3.pmadd.ub.sw.128" => "__builtin_ia32_pmaddubsw128",
+            "llvm.x86.ssse3.pmul.hr.sw" => "__builtin_ia32_pmulhrsw",
+            "llvm.x86.ssse3.pmul.hr.sw.128" => "__builtin_ia32_pmulhrsw128",
+            "llvm.x86.ssse3.pshuf.b" => "__builtin_ia32_pshufb",
+            "llvm.x86.ssse3.pshuf.b.128" => "__builtin_ia32_pshufb128",
+            "llvm.x86.ssse3.psign.b" => "__builtin_ia32_psignb",
+            "llvm.x86.ssse3.psign.b.128" => "__builtin_ia32_psignb128",
+            "llvm.x86.ssse3.psign.d" => "__builtin_ia32_psignd",
+            "llvm.x86.ssse3.psign.d.128" => "__builtin_ia32_psignd128",
+            "llvm.x86.ssse3.psign.w" => "__builtin_ia32_psignw",
+            "llvm.x86.ssse3.psign.w.128" => "__builtin_ia32_psignw128",
+            "llvm.x86.stui" => "__builtin_ia32_stui",
+            "llvm.x86.tbm.bextri.u32" => "__builtin_ia32_bextri_u32",
+            "llvm.x86.tbm.bextri.u64" => "__builtin_ia32_bextri_u64",
+            "llvm.x86.testui" => "__builtin_ia32_testui",
+            "llvm.x86.tpause" => "__builtin_ia32_tpause",
+            "llvm.x86.umonitor" => "__builtin_ia32_umonitor",
+            "llvm.x86.umwait" => "__builtin_ia32_umwait",
+            "llvm.x86.vcvtps2ph.128" => "__builtin_ia32_vcvtps2ph",
+            "llvm.x86.vcvtps2ph.256" => "__builtin_ia32_vcvtps2ph256",
+            "llvm.x86.vgf2p8affineinvqb.128" => "__builtin_ia32_vgf2p8affineinvqb_v16qi",
+            "llvm.x86.vgf2p8affineinvqb.256" => "__builtin_ia32_vgf2p8affineinvqb_v32qi",
+            "llvm.x86.vgf2p8affineinvqb.512" => "__builtin_ia32_vgf2p8affineinvqb_v64qi",
+            "llvm.x86.vgf2p8affineqb.128" => "__builtin_ia32_vgf2p8affineqb_v16qi",
+            "llvm.x86.vgf2p8affineqb.256" => "__builtin_ia32_vgf2p8affineqb_v32qi",
+            "llvm.x86.vgf2p8affineqb.512" => "__builtin_ia32_vgf2p8affineqb_v64qi",
+            "llvm.x86.vgf2p8mulb.128" => "__builtin_ia32_vgf2p8mulb_v16qi",
+            "llvm.x86.vgf2p8mulb.256" => "__builtin_ia32_vgf2p8mulb_v32qi",
+            "llvm.x86.vgf2p8mulb.512" => "__builtin_ia32_vgf2p8mulb_v64qi",
+            "llvm.x86.wbinvd" => "__builtin_ia32_wbinvd",
+            "llvm.x86.wbnoinvd" => "__builtin_ia32_wbnoinvd",
+            "llvm.x86.wrfsbase.32" => "__builtin_ia32_wrfsbase32",
+            "llvm.x86.wrfsbase.64" => "__builtin_ia32_wrfsbase64",
+            "llvm.x86.wrgsbase.32" => "__builtin_ia32_wrgsbase32",
+            "llvm.x86.wrgsbase.64" => "__builtin_ia32_wrgsbase64",
+            "llvm.x86.wrpkru" => "__builtin_ia32_wrpkru",
+            "llvm.x86.wrssd" => "__builtin_ia32_wrssd",
+            "llvm.x86.wrssq" => "__builtin_ia32_wrssq",
+            "llvm.x86.wrussd" => "__builtin_ia32_wrussd",
+            "llvm.x86.wrussq" => "__builtin_ia32_wrussq",
+            "llvm.x86.xabort" => "__builtin_ia32_xabort",
+            "llvm.x86.xbegin" => "__builtin_ia32_xbegin",
+            "llvm.x86.xend" => "__builtin_ia32_xend",
+            "llvm.x86.xop.vfrcz.pd" => "__builtin_ia32_vfrczpd",
+            "llvm.x86.xop.vfrcz.pd.256" => "__builtin_ia32_vfrczpd256",
+            "llvm.x86.xop.vfrcz.ps" => "__builtin_ia32_vfrczps",
+            "llvm.x86.xop.vfrcz.ps.256" => "__builtin_ia32_vfrczps256",
+            "llvm.x86.xop.vfrcz.sd" => "__builtin_ia32_vfrczsd",
+            "llvm.x86.xop.vfrcz.ss" => "__builtin_ia32_vfrczss",
+            "llvm.x86.xop.vpermil2pd" => "__builtin_ia32_vpermil2pd",
+            "llvm.x86.xop.vpermil2pd.256" => "__builtin_ia32_vpermil2pd256",
+            "llvm.x86.xop.vpermil2ps" => "__builtin_ia32_vpermil2ps",
+            "llvm.x86.xop.vpermil2ps.256" => "__builtin_ia32_vpermil2ps256",
+            "llvm.x86.xop.vphaddbd" => "__builtin_ia32_vphaddbd",
+            "llvm.x86.xop.vphaddbq" => "__builtin_ia32_vphaddbq",
+            "llvm.x86.xop.vphaddbw" => "__builtin_ia32_vphaddbw",
+            "llvm.x86.xop.vphadddq" => "__builtin_ia32_vphadddq",
+            "llvm.x86.xop.vphaddubd" => "__builtin_ia32_vphaddubd",
+            "llvm.x86.xop.vphaddubq" => "__builtin_ia32_vphaddubq",
+            "llvm.x86.xop.vphaddubw" => "__builtin_ia32_vphaddubw",
+            "llvm.x86.xop.vphaddudq" => "__builtin_ia32_vphaddudq",
+            "llvm.x86.xop.vphadduwd" => "__builtin_ia32_vphadduwd",
+            "llvm.x86.xop.vphadduwq" => "__builtin_ia32_vphadduwq",
+            "llvm.x86.xop.vphaddwd" => "__builtin_ia32_vphaddwd",
+            "llvm.x86.xop.vphaddwq" => "__builtin_ia32_vphaddwq",
+            "llvm.x86.xop.vphsubbw" => "__builtin_ia32_vphsubbw",
+            "llvm.x86.xop.vphsubdq" => "__builtin_ia32_vphsubdq",
+            "llvm.x86.xop.vphsubwd" => "__builtin_ia32_vphsubwd",
+            "llvm.x86.xop.vpmacsdd" => "__builtin_ia32_vpmacsdd",
+            "llvm.x86.xop.vpmacsdqh" => "__builtin_ia32_vpmacsdqh",
+            "llvm.x86.xop.vpmacsdql" => "__builtin_ia32_vpmacsdql",
+            "llvm.x86.xop.vpmacssdd" => "__builtin_ia32_vpmacssdd",
+            "llvm.x86.xop.vpmacssdqh" => "__builtin_ia32_vpmacssdqh",
+            "llvm.x86.xop.vpmacssdql" => "__builtin_ia32_vpmacssdql",
+            "llvm.x86.xop.vpmacsswd" => "__builtin_ia32_vpmacsswd",
+            "llvm.x86.xop.vpmacssww" => "__builtin_ia32_vpmacssww",
+            "llvm.x86.xop.vpmacswd" => "__builtin_ia32_vpmacswd",
+            "llvm.x86.xop.vpmacsww" => "__builtin_ia32_vpmacsww",
+            "llvm.x86.xop.vpmadcsswd" => "__builtin_ia32_vpmadcsswd",
+            "llvm.x86.xop.vpmadcswd" => "__builtin_ia32_vpmadcswd",
+            "llvm.x86.xop.vpperm" => "__builtin_ia32_vpperm",
+            "llvm.x86.xop.vpshab" => "__builtin_ia32_vpshab",
+            "llvm.x86.xop.vpshad" => "__builtin_ia32_vpshad",
+            "llvm.x86.xop.vpshaq" => "__builtin_ia32_vpshaq",
+            "llvm.x86.xop.vpshaw" => "__builtin_ia32_vpshaw",
+            "llvm.x86.xop.vpshlb" => "__builtin_ia32_vpshlb",
+            "llvm.x86.xop.vpshld" => "__builtin_ia32_vpshld",
+            "llvm.x86.xop.vpshlq" => "__builtin_ia32_vpshlq",
+            "llvm.x86.xop.vpshlw" => "__builtin_ia32_vpshlw",
+            "llvm.x86.xresldtrk" => "__builtin_ia32_xresldtrk",
+            "llvm.x86.xsusldtrk" => "__builtin_ia32_xsusldtrk",
+            "llvm.x86.xtest" => "__builtin_ia32_xtest",
+            _ => return None,
+        };
+    Some(gcc_name)
+}
+
+/// Whether the GCC builtin `name` is the `_mask` version of the builtin of an LLVM intrinsic,
+/// and so takes a merge source and a mask that the intrinsic does not take.
+pub fn is_unmasked_intrinsic_builtin(name: &str) -> bool {
+    matches!(name,
+        "__builtin_ia32_addpd512_mask"
+        | "__builtin_ia32_addps512_mask"
+        | "__builtin_ia32_dbpsadbw128_mask"
+        | "__builtin_ia32_dbpsadbw256_mask"
+        | "__builtin_ia32_dbpsadbw512_mask"
+        | "__builtin_ia32_divpd512_mask"
+        | "__builtin_ia32_divps512_mask"
+        | "__builtin_ia32_exp2pd_mask"
+        | "__builtin_ia32_exp2ps_mask"
+        | "__builtin_ia32_maxpd512_mask"
+        | "__builtin_ia32_maxps512_mask"
+        | "__builtin_ia32_minpd512_mask"
+        | "__builtin_ia32_minps512_mask"
+        | "__builtin_ia32_mulpd512_mask"
+        | "__builtin_ia32_mulps512_mask"
+        | "__builtin_ia32_packssdw512_mask"
+        | "__builtin_ia32_packsswb512_mask"
+        | "__builtin_ia32_packusdw512_mask"
+        | "__builtin_ia32_packuswb512_mask"
+        | "__builtin_ia32_pavgb512_mask"
+        | "__builtin_ia32_pavgw512_mask"
+        | "__builtin_ia32_permvardf256_mask"
+        | "__builtin_ia32_permvardf512_mask"
+        | "__builtin_ia32_permvardi256_mask"
+        | "__builtin_ia32_permvardi512_mask"
+        | "__builtin_ia32_permvarhi128_mask"
+        | "__builtin_ia32_permvarhi256_mask"
+        | "__builtin_ia32_permvarhi512_mask"
+        | "__builtin_ia32_permvarqi128_mask"
+        | "__builtin_ia32_permvarqi256_mask"
+        | "__builtin_ia32_permvarqi512_mask"
+        | "__builtin_ia32_permvarsf512_mask"
+        | "__builtin_ia32_permvarsi512_mask"
+        | "__builtin_ia32_pmaddubsw512_mask"
+        | "__builtin_ia32_pmaddwd512_mask"
+        | "__builtin_ia32_pmulhrsw512_mask"
+        | "__builtin_ia32_pmulhuw512_mask"
+        | "__builtin_ia32_pmulhw512_mask"
+        | "__builtin_ia32_pshufb512_mask"
+        | "__builtin_ia32_pslld512_mask"
+        | "__builtin_ia32_pslldi512_mask"
+        | "__builtin_ia32_psllq512_mask"
+        | "__builtin_ia32_psllqi512_mask"
+        | "__builtin_ia32_psllv16hi_mask"
+        | "__builtin_ia32_psllv16si_mask"
+        | "__builtin_ia32_psllv32hi_mask"
+        | "__builtin_ia32_psllv8di_mask"
+        | "__builtin_ia32_psllv8hi_mask"
+        | "__builtin_ia32_psllw512_mask"
+        | "__builtin_ia32_psllwi512_mask"
+        | "__builtin_ia32_psrad512_mask"
+        | "__builtin_ia32_psradi512_mask"
+        | "__builtin_ia32_psraq128_mask"
+        | "__builtin_ia32_psraq256_mask"
+        | "__builtin_ia32_psraq512_mask"
+        | "__builtin_ia32_psraqi128_mask"
+        | "__builtin_ia32_psraqi256_mask"
+        | "__builtin_ia32_psraqi512_mask"
+        | "__builtin_ia32_psrav16hi_mask"
+        | "__builtin_ia32_psrav16si_mask"
+        | "__builtin_ia32_psrav32hi_mask"
+        | "__builtin_ia32_psrav8di_mask"
+        | "__builtin_ia32_psrav8hi_mask"
+        | "__builtin_ia32_psravq128_mask"
+        | "__builtin_ia32_psravq256_mask"
+        | "__builtin_ia32_psraw512_mask"
+        | "__builtin_ia32_psrawi512_mask"
+        | "__builtin_ia32_psrld512_mask"
+        | "__builtin_ia32_psrldi512_mask"
+        | "__builtin_ia32_psrlq512_mask"
+        | "__builtin_ia32_psrlqi512_mask"
+        | "__builtin_ia32_psrlv16hi_mask"
+        | "__builtin_ia32_psrlv16si_mask"
+        | "__builtin_ia32_psrlv32hi_mask"
+        | "__builtin_ia32_psrlv8di_mask"
+        | "__builtin_ia32_psrlv8hi_mask"
+        | "__builtin_ia32_psrlw512_mask"
+        | "__builtin_ia32_psrlwi512_mask"
+        | "__builtin_ia32_pternlogd128_mask"
+        | "__builtin_ia32_pternlogd256_mask"
+        | "__builtin_ia32_pternlogd512_mask"
+        | "__builtin_ia32_pternlogq128_mask"
+        | "__builtin_ia32_pternlogq256_mask"
+        | "__builtin_ia32_pternlogq512_mask"
+        | "__builtin_ia32_rcp14pd128_mask"
+        | "__builtin_ia32_rcp14pd256_mask"
+        | "__builtin_ia32_rcp14pd512_mask"
+        | "__builtin_ia32_rcp14ps128_mask"
+        | "__builtin_ia32_rcp14ps256_mask"
+        | "__builtin_ia32_rcp14ps512_mask"
+        | "__builtin_ia32_rcp14sd_mask"
+        | "__builtin_ia32_rcp14ss_mask"
+        | "__builtin_ia32_rcp28pd_mask"
+        | "__builtin_ia32_rcp28ps_mask"
+        | "__builtin_ia32_rsqrt14pd128_mask"
+        | "__builtin_ia32_rsqrt14pd256_mask"
+        | "__builtin_ia32_rsqrt14pd512_mask"
+        | "__builtin_ia32_rsqrt14ps128_mask"
+        | "__builtin_ia32_rsqrt14ps256_mask"
+        | "__builtin_ia32_rsqrt14ps512_mask"
+        | "__builtin_ia32_rsqrt14sd_mask"
+        | "__builtin_ia32_rsqrt14ss_mask"
+        | "__builtin_ia32_rsqrt28pd_mask"
+        | "__builtin_ia32_rsqrt28ps_mask"
+        | "__builtin_ia32_subpd512_mask"
+        | "__builtin_ia32_subps512_mask"
+        | "__builtin_ia32_vpconflictdi_128_mask"
+        | "__builtin_ia32_vpconflictdi_256_mask"
+        | "__builtin_ia32_vpconflictdi_512_mask"
+        | "__builtin_ia32_vpconflictsi_128_mask"
+        | "__builtin_ia32_vpconflictsi_256_mask"
+        | "__builtin_ia32_vpconflictsi_512_mask"
+        | "__builtin_ia32_vpermi2vard128_mask"
+        | "__builtin_ia32_vpermi2vard256_mask"
+        | "__builtin_ia32_vpermi2vard512_mask"
+        | "__builtin_ia32_vpermi2varhi128_mask"
+        | "__builtin_ia32_vpermi2varhi256_mask"
+        | "__builtin_ia32_vpermi2varhi512_mask"
+        | "__builtin_ia32_vpermi2varpd128_mask"
+        | "__builtin_ia32_vpermi2varpd256_mask"
+        | "__builtin_ia32_vpermi2varpd512_mask"
+        | "__builtin_ia32_vpermi2varps128_mask"
+        | "__builtin_ia32_vpermi2varps256_mask"
+        | "__builtin_ia32_vpermi2varps512_mask"
+        | "__builtin_ia32_vpermi2varq128_mask"
+        | "__builtin_ia32_vpermi2varq256_mask"
+        | "__builtin_ia32_vpermi2varq512_mask"
+        | "__builtin_ia32_vpermi2varqi128_mask"
+        | "__builtin_ia32_vpermi2varqi256_mask"
+        | "__builtin_ia32_vpermi2varqi512_mask"
+        | "__builtin_ia32_vpermilvarpd512_mask"
+        | "__builtin_ia32_vpermilvarps512_mask"
+        | "__builtin_ia32_vpmadd52huq128_mask"
+        | "__builtin_ia32_vpmadd52huq256_mask"
+        | "__builtin_ia32_vpmadd52huq512_mask"
+        | "__builtin_ia32_vpmadd52luq128_mask"
+        | "__builtin_ia32_vpmadd52luq256_mask"
+        | "__builtin_ia32_vpmadd52luq512_mask"
+        | "__builtin_ia32_vpmultishiftqb128_mask"
+        | "__builtin_ia32_vpmultishiftqb256_mask"
+        | "__builtin_ia32_vpmultishiftqb512_mask"
+    )
+}
@@ -1,73 +1,233 @@
-use gccjit::Function;
+use std::borrow::Cow;
 
+use gccjit::{BinaryOp, Function, RValue, ToRValue, Type};
+
+use crate::builder::Builder;
+use crate::common::TypeReflection;
 use crate::context::CodegenCx;
+use crate::intrinsic::archs;
+
+/// Some LLVM intrinsics do not map 1-to-1 to the GCC builtins, so the arguments are adjusted here.
+pub fn adjust_intrinsic_arguments<'a, 'b, 'gcc, 'tcx>(builder: &Builder<'a, 'gcc, 'tcx>, gcc_func: Function<'gcc>, mut args: Cow<'b, [RValue<'gcc>]>, func_name: &str) -> Cow<'b, [RValue<'gcc>]> {
+    let param_count = gcc_func.get_param_count();
+    match func_name {
+        // NOTE: the LLVM intrinsics return the carry flag and the result, while the GCC builtins
+        // write the result through a pointer. See adjust_intrinsic_return_value().
+        "__builtin_ia32_addcarryx_u32" | "__builtin_ia32_addcarryx_u64" | "__builtin_ia32_sbb_u32" | "__builtin_ia32_sbb_u64" => {
+            let mut new_args = args.to_vec();
+            let result = builder.current_func().new_local(None, new_args[1].get_type(), "carry_result");
+            new_args.push(result.get_address(None));
+            args = new_args.into();
+        },
+        // NOTE: same for the random number and its success status.
+        "__builtin_ia32_rdrand16_step" | "__builtin_ia32_rdrand32_step" | "__builtin_ia32_rdrand64_step"
+        | "__builtin_ia32_rdseed_hi_step" | "__builtin_ia32_rdseed_si_step" | "__builtin_ia32_rdseed_di_step" => {
+            let param_type = gcc_func.get_param(0).to_rvalue().get_type();
+            let pointee_type = param_type.get_pointee().expect("pointer type");
+            let result = builder.current_func().new_local(None, pointee_type, "random_number");
+            args = vec![result.get_address(None)].into();
+        },
+        // NOTE: the LLVM intrinsics take the 64-bit mask in two 32-bit halves (high, low), while the
+        // GCC builtins take a single 64-bit mask.
+        "__builtin_ia32_xsave" | "__builtin_ia32_xsave64" | "__builtin_ia32_xrstor" | "__builtin_ia32_xrstor64"
+        | "__builtin_ia32_xsavec" | "__builtin_ia32_xsavec64" | "__builtin_ia32_xsaveopt" | "__builtin_ia32_xsaveopt64"
+        | "__builtin_ia32_xsaves" | "__builtin_ia32_xsaves64" | "__builtin_ia32_xrstors" | "__builtin_ia32_xrstors64"
+        | "__builtin_ia32_xsetbv" => {
+            let mask_index = args.len() - 2;
+            let mask = combine_halves(builder, gcc_func.get_param(mask_index as i32).to_rvalue().get_type(), args[mask_index], args[mask_index + 1]);
+            let mut new_args = args[..mask_index].to_vec();
+            new_args.push(mask);
+            args = new_args.into();
+        },
+        // NOTE: the LLVM intrinsic returns the timestamp and the processor id, while the GCC
+        // builtin writes the processor id through a pointer.
+        "__builtin_ia32_rdtscp" => {
+            let param_type = gcc_func.get_param(0).to_rvalue().get_type();
+            let pointee_type = param_type.get_pointee().expect("pointer type");
+            let result = builder.current_func().new_local(None, pointee_type, "processor_id");
+            args = vec![result.get_address(None)].into();
+        },
+        // NOTE: the LLVM intrinsics take the immediate after the merge source and the mask, while
+        // the GCC builtins take it before.
+        "__builtin_ia32_rndscaless_mask_round" | "__builtin_ia32_rndscalesd_mask_round"
+        | "__builtin_ia32_rangess128_mask_round" | "__builtin_ia32_rangesd128_mask_round" => {
+            args = vec![args[0], args[1], args[4], args[2], args[3], args[5]].into();
+        },
+        // NOTE: the LLVM intrinsics take scalars, while the GCC builtins take vectors and operate
+        // on their first element. See adjust_intrinsic_return_value().
+        "__builtin_ia32_vfmaddss3_round" | "__builtin_ia32_vfmaddsd3_round" => {
+            let new_args = args.iter().enumerate()
+                .map(|(index, &arg)| {
+                    let param_type = gcc_func.get_param(index as i32).to_rvalue().get_type();
+                    match param_type.dyncast_vector() {
+                        Some(vector_type) => {
+                            let element_type = vector_type.get_element_type();
+                            let zero = builder.context.new_rvalue_zero(element_type);
+                            let mut elements = vec![zero; vector_type.get_num_units()];
+                            elements[0] = arg;
+                            builder.context.new_rvalue_from_vector(None, param_type, &elements)
+                        },
+                        None => arg,
+                    }
+                })
+                .collect::<Vec<_>>();
+            args = new_args.into();
+        },
+        // NOTE: many LLVM intrinsics are the unmasked versions of GCC builtins that also take a
+        // merge source and a mask (the `_mask` suffix). Those come right before the first argument
+        // that the builtin expects to be a vector or a mask (the rounding mode), or at the end.
+        // The merge source is unused since all the lanes are selected by the mask.
+        _ if is_unmasked_intrinsic_builtin(func_name) && param_count > args.len() => {
+            let param_type = |index: usize| gcc_func.get_param(index as i32).to_rvalue().get_type();
+            let position = (0..args.len())
+                .find(|&index| {
+                    let arg_type = args[index].get_type();
+                    let param_type = param_type(index);
+                    !arg_type.is_vector() && (param_type.is_vector() || arg_type.get_size() != param_type.get_size())
+                })
+                .unwrap_or(args.len());
+            let mut new_args = args.to_vec();
+            for index in position..position + param_count - args.len() {
+                let param_type = param_type(index);
+                let arg =
+                    if param_type.is_vector() {
+                        builder.current_func().new_local(None, param_type, "undefined_for_intrinsic").to_rvalue()
+                    }
+                    else {
+                        builder.context.new_rvalue_from_int(param_type, -1)
+                    };
+                new_args.insert(index, arg);
+            }
+            args = new_args.into();
+        },
+        _ => (),
+    }
+
+    args
+}
+
+/// Whether the GCC builtin `name` takes a merge source and a mask that the LLVM intrinsic mapped to
+/// it does not take.
+fn is_unmasked_intrinsic_builtin(name: &str) -> bool {
+    match name {
+        // NOTE: those are mapped in intrinsic() below.
+        "__builtin_ia32_fpclassps128_mask" | "__builtin_ia32_fpclassps256_mask" | "__builtin_ia32_fpclassps512_mask"
+        | "__builtin_ia32_fpclasspd128_mask" | "__builtin_ia32_fpclasspd256_mask" | "__builtin_ia32_fpclasspd512_mask"
+        | "__builtin_ia32_sqrtps512_mask" | "__builtin_ia32_sqrtpd512_mask"
+        | "__builtin_ia32_vfmaddps512_mask" | "__builtin_ia32_vfmaddpd512_mask"
+        | "__builtin_ia32_vfmaddsubps512_mask" | "__builtin_ia32_vfmaddsubpd512_mask"
+        | "__builtin_ia32_cvtdq2ps512_mask" | "__builtin_ia32_cvtudq2ps512_mask"
+        | "__builtin_ia32_cvtqq2pd512_mask" | "__builtin_ia32_cvtuqq2pd512_mask"
+        | "__builtin_ia32_cvtqq2ps512_mask" | "__builtin_ia32_cvtuqq2ps512_mask"
+        | "__builtin_ia32_vpshufbitqmb128_mask" | "__builtin_ia32_vpshufbitqmb256_mask"
+        | "__builtin_ia32_vpshufbitqmb512_mask" => true,
+        _ => archs::is_unmasked_intrinsic_builtin(name),
+    }
+}
+
+/// Converts the return value of the GCC builtin to the return value of the LLVM intrinsic when
+/// they differ. `args` are the arguments after adjust_intrinsic_arguments().
+pub fn adjust_intrinsic_return_value<'a, 'gcc, 'tcx>(builder: &Builder<'a, 'gcc, 'tcx>, return_value: RValue<'gcc>, func_name: &str, args: &[RValue<'gcc>]) -> RValue<'gcc> {
+    match func_name {
+        "__builtin_ia32_addcarryx_u32" | "__builtin_ia32_addcarryx_u64" | "__builtin_ia32_sbb_u32" | "__builtin_ia32_sbb_u64" => {
+            let result = args.last().expect("last arg").dereference(None).to_rvalue();
+            intrinsic_pair(builder, return_value, result)
+        },
+        "__builtin_ia32_rdrand16_step" | "__builtin_ia32_rdrand32_step" | "__builtin_ia32_rdrand64_step"
+        | "__builtin_ia32_rdseed_hi_step" | "__builtin_ia32_rdseed_si_step" | "__builtin_ia32_rdseed_di_step" => {
+            let random_number = args[0].dereference(None).to_rvalue();
+            intrinsic_pair(builder, random_number, return_value)
+        },
+        "__builtin_ia32_rdtscp" => {
+            let processor_id = args[0].dereference(None).to_rvalue();
+            intrinsic_pair(builder, return_value, processor_id)
+        },
+        "__builtin_ia32_vfmaddss3_round" | "__builtin_ia32_vfmaddsd3_round" => {
+            let zero = builder.context.new_rvalue_zero(builder.int_type);
+            builder.context.new_vector_access(None, return_value, zero).to_rvalue()
+        },
+        // NOTE: the LLVM intrinsics return at least an i32, while the GCC builtins return an integer
+        // twice as wide as the elements, i.e. a 16-bit integer for the vectors of 8-bit elements.
+        _ if (func_name.starts_with("__builtin_aarch64_saddlv") || func_name.starts_with("__builtin_aarch64_uaddlv"))
+            && return_value.get_type().get_size() < 4 => {
+            builder.context.new_cast(None, return_value, builder.i32_type)
+        },
+        _ => return_value,
+    }
+}
+
+/// Returns `(high << 32) | low` as a value of type `typ`.
+fn combine_halves<'a, 'gcc, 'tcx>(builder: &Builder<'a, 'gcc, 'tcx>, typ: Type<'gcc>, high: RValue<'gcc>, low: RValue<'gcc>) -> RValue<'gcc> {
+    let high = builder.context.new_cast(None, high, builder.cx.uint_type);
+    let low = builder.context.new_cast(None, low, builder.cx.uint_type);
+    let high = builder.context.new_cast(None, high, typ);
+    let low = builder.context.new_cast(None, low, typ);
+    let shift = builder.context.new_rvalue_from_int(typ, 32);
+    let high = builder.context.new_binary_op(None, BinaryOp::LShift, typ, high, shift);
+    builder.context.new_binary_op(None, BinaryOp::BitwiseOr, typ, high, low)
+}
+
+/// Creates the struct returned by the LLVM intrinsics that return two values.
+fn intrinsic_pair<'a, 'gcc, 'tcx>(builder: &Builder<'a, 'gcc, 'tcx>, first: RValue<'gcc>, second: RValue<'gcc>) -> RValue<'gcc> {
+    let first_field = builder.context.new_field(None, first.get_type(), "first");
+    let second_field = builder.context.new_field(None, second.get_type(), "second");
+    let struct_type = builder.context.new_struct_type(None, "intrinsic_pair", &[first_field, second_field]);
+    let pair = builder.current_func().new_local(None, struct_type.as_type(), "pair");
+    builder.block.add_assignment(None, pair.access_field(None, first_field), first);
+    builder.block.add_assignment(None, pair.access_field(None, second_field), second);
+    pair.to_rvalue()
+}
 
 pub fn intrinsic<'gcc, 'tcx>(name: &str, cx: &CodegenCx<'gcc, 'tcx>) -> Function<'gcc> {
+    if name.starts_with("llvm.aarch64.") {
+        let gcc_name = aarch64_builtin(name).unwrap_or_else(|| unsupported_intrinsic(cx, name));
+        return builtin_function(cx, &gcc_name);
+    }
+
     let gcc_name =
         match name {
             "llvm.x86.xgetbv" => "__builtin_ia32_xgetbv",
             // NOTE: this doc specifies the equivalent GCC builtins: http://huonw.github.io/llvmint/llvmint/x86/index.html
-            "llvm.x86.sse2.pmovmskb.128" => "__builtin_ia32_pmovmskb128",
-            "llvm.x86.avx2.pmovmskb" => "__builtin_ia32_pmovmskb256",
+            "llvm.sqrt.v2f64" => "__builtin_ia32_sqrtpd",
+            "llvm.x86.sse.cmp.ps" => "__builtin_ia32_cmpps",
             "llvm.x86.sse2.cmp.pd" => "__builtin_ia32_cmppd",
-            "llvm.x86.sse2.movmsk.pd" => "__builtin_ia32_movmskpd",
-            "llvm.x86.ssse3.pshuf.b.128" => "__builtin_ia32_pshufb128",
-            "llvm.x86.sse2.pause" => "__builtin_ia32_pause",
-            "llvm.x86.avx2.pshuf.b" => "__builtin_ia32_pshufb256",
-            "llvm.x86.avx2.pslli.d" => "__builtin_ia32_pslldi256",
-            "llvm.x86.avx2.psrli.d" => "__builtin_ia32_psrldi256",
-            "llvm.x86.sse2.pslli.q" => "__builtin_ia32_psllqi128",
-            "llvm.x86.avx.vzeroupper" => "__builtin_ia32_vzeroupper",
+            "llvm.x86.avx.cmp.ps.256" => "__builtin_ia32_cmpps256",
+            "llvm.x86.avx.cmp.pd.256" => "__builtin_ia32_cmppd256",
+            "llvm.x86.addcarry.32" => "__builtin_ia32_addcarryx_u32",
+            "llvm.x86.addcarry.64" => "__builtin_ia32_addcarryx_u64",
+            "llvm.x86.subborrow.32" => "__builtin_ia32_sbb_u32",
+            "llvm.x86.subborrow.64" => "__builtin_ia32_sbb_u64",
+            "llvm.x86.rdrand.16" => "__builtin_ia32_rdrand16_step",
+            "llvm.x86.rdrand.32" => "__builtin_ia32_rdrand32_step",
+            "llvm.x86.rdrand.64" => "__builtin_ia32_rdrand64_step",
+            "llvm.x86.rdseed.16" => "__builtin_ia32_rdseed_hi_step",
+            "llvm.x86.rdseed.32" => "__builtin_ia32_rdseed_si_step",
+            "llvm.x86.rdseed.64" => "__builtin_ia32_rdseed_di_step",
+            "llvm.x86.rdtscp" => "__builtin_ia32_rdtscp",
+            "llvm.x86.xsave" => "__builtin_ia32_xsave",
+            "llvm.x86.xsave64" => "__builtin_ia32_xsave64",
+            "llvm.x86.xrstor" => "__builtin_ia32_xrstor",
+            "llvm.x86.xrstor64" => "__builtin_ia32_xrstor64",
+            "llvm.x86.xsavec" => "__builtin_ia32_xsavec",
+            "llvm.x86.xsavec64" => "__builtin_ia32_xsavec64",
+            "llvm.x86.xsaveopt" => "__builtin_ia32_xsaveopt",
+            "llvm.x86.xsaveopt64" => "__builtin_ia32_xsaveopt64",
+            "llvm.x86.xsaves" => "__builtin_ia32_xsaves",
+            "llvm.x86.xsaves64" => "__builtin_ia32_xsaves64",
+            "llvm.x86.xrstors" => "__builtin_ia32_xrstors",
+            "llvm.x86.xrstors64" => "__builtin_ia32_xrstors64",
+            "llvm.x86.xsetbv" => "__builtin_ia32_xsetbv",
+            "llvm.x86.avx512.mask.sqrt.ss" => "__builtin_ia32_sqrtss_mask_round",
+            "llvm.x86.avx512.mask.sqrt.sd" => "__builtin_ia32_sqrtsd_mask_round",
+
+            // The following LLVM intrinsics were removed from LLVM (they are auto-upgraded), but
+            // stdarch can still use them.
             "llvm.x86.avx2.vperm2i128" => "__builtin_ia32_permti256",
-            "llvm.x86.avx2.psrli.w" => "__builtin_ia32_psrlwi256",
             "llvm.x86.sse2.storeu.dq" => "__builtin_ia32_storedqu",
-            "llvm.x86.sse2.psrli.w" => "__builtin_ia32_psrlwi128",
             "llvm.x86.avx2.pabs.d" => "__builtin_ia32_pabsd256",
-            "llvm.x86.sse2.psrli.q" => "__builtin_ia32_psrlqi128",
             "llvm.x86.avx2.pabs.w" => "__builtin_ia32_pabsw256",
-            "llvm.x86.avx2.pblendvb" => "__builtin_ia32_pblendvb256",
             "llvm.x86.avx2.pabs.b" => "__builtin_ia32_pabsb256",
-            "llvm.x86.avx2.psrli.q" => "__builtin_ia32_psrlqi256",
-            "llvm.x86.sse41.pblendvb" => "__builtin_ia32_pblendvb128",
             "llvm.x86.sse41.pblendw" => "__builtin_ia32_pblendw128",
-            "llvm.x86.sse42.crc32.32.8" => "__builtin_ia32_crc32qi",
-            "llvm.x86.sse42.crc32.32.16" => "__builtin_ia32_crc32hi",
-            "llvm.x86.sse42.crc32.32.32" => "__builtin_ia32_crc32si",
-            "llvm.x86.sse42.crc32.64.64" => "__builtin_ia32_crc32di",
-            "llvm.x86.avx2.pavg.w" => "__builtin_ia32_pavgw256",
-            "llvm.x86.avx2.pavg.b" => "__builtin_ia32_pavgb256",
-            "llvm.x86.avx2.phadd.w" => "__builtin_ia32_phaddw256",
-            "llvm.x86.avx2.phadd.d" => "__builtin_ia32_phaddd256",
-            "llvm.x86.avx2.phadd.sw" => "__builtin_ia32_phaddsw256",
-            "llvm.x86.avx2.phsub.w" => "__builtin_ia32_phsubw256",
-            "llvm.x86.avx2.phsub.d" => "__builtin_ia32_phsubd256",
-            "llvm.x86.avx2.phsub.sw" => "__builtin_ia32_phsubsw256",
-            "llvm.x86.avx2.gather.d.d" => "__builtin_ia32_gatherd_d",
-            "llvm.x86.avx2.gather.d.d.256" => "__builtin_ia32_gatherd_d256",
-            "llvm.x86.avx2.gather.d.ps" => "__builtin_ia32_gatherd_ps",
-            "llvm.x86.avx2.gather.d.ps.256" => "__builtin_ia32_gatherd_ps256",
-            "llvm.x86.avx2.gather.d.q" => "__builtin_ia32_gatherd_q",
-            "llvm.x86.avx2.gather.d.q.256" => "__builtin_ia32_gatherd_q256",
-            "llvm.x86.avx2.gather.d.pd" => "__builtin_ia32_gatherd_pd",
-            "llvm.x86.avx2.gather.d.pd.256" => "__builtin_ia32_gatherd_pd256",
-            "llvm.x86.avx2.gather.q.d" => "__builtin_ia32_gatherq_d",
-            "llvm.x86.avx2.gather.q.d.256" => "__builtin_ia32_gatherq_d256",
-            "llvm.x86.avx2.gather.q.ps" => "__builtin_ia32_gatherq_ps",
-            "llvm.x86.avx2.gather.q.ps.256" => "__builtin_ia32_gatherq_ps256",
-            "llvm.x86.avx2.gather.q.q" => "__builtin_ia32_gatherq_q",
-            "llvm.x86.avx2.gather.q.q.256" => "__builtin_ia32_gatherq_q256",
-            "llvm.x86.avx2.gather.q.pd" => "__builtin_ia32_gatherq_pd",
-            "llvm.x86.avx2.gather.q.pd.256" => "__builtin_ia32_gatherq_pd256",
-            "llvm.x86.avx2.pmadd.wd" => "__builtin_ia32_pmaddwd256",
-            "llvm.x86.avx2.pmadd.ub.sw" => "__builtin_ia32_pmaddubsw256",
-            "llvm.x86.avx2.maskload.d" => "__builtin_ia32_maskloadd",
-            "llvm.x86.avx2.maskload.d.256" => "__builtin_ia32_maskloadd256",
-            "llvm.x86.avx2.maskload.q" => "__builtin_ia32_maskloadq",
-            "llvm.x86.avx2.maskload.q.256" => "__builtin_ia32_maskloadq256",
-            "llvm.x86.avx2.maskstore.d" => "__builtin_ia32_maskstored",
-            "llvm.x86.avx2.maskstore.d.256" => "__builtin_ia32_maskstored256",
-            "llvm.x86.avx2.maskstore.q" => "__builtin_ia32_maskstoreq",
-            "llvm.x86.avx2.maskstore.q.256" => "__builtin_ia32_maskstoreq256",
             "llvm.x86.avx2.pmaxs.w" => "__builtin_ia32_pmaxsw256",
             "llvm.x86.avx2.pmaxs.d" => "__builtin_ia32_pmaxsd256",
             "llvm.x86.avx2.pmaxs.b" => "__builtin_ia32_pmaxsb256",
@@ -80,59 +240,393 @@ pub fn intrinsic<'gcc, 'tcx>(name: &str, cx: &CodegenCx<'gcc, 'tcx>) -> Function
             "llvm.x86.avx2.pminu.w" => "__builtin_ia32_pminuw256",
             "llvm.x86.avx2.pminu.d" => "__builtin_ia32_pminud256",
             "llvm.x86.avx2.pminu.b" => "__builtin_ia32_pminub256",
-            "llvm.x86.avx2.mpsadbw" => "__builtin_ia32_mpsadbw256",
             "llvm.x86.avx2.pmul.dq" => "__builtin_ia32_pmuldq256",
             "llvm.x86.avx2.pmulu.dq" => "__builtin_ia32_pmuludq256",
-            "llvm.x86.avx2.pmulh.w" => "__builtin_ia32_pmulhw256",
-            "llvm.x86.avx2.pmulhu.w" => "__builtin_ia32_pmulhuw256",
-            "llvm.x86.avx2.pmul.hr.sw" => "__builtin_ia32_pmulhrsw256",
-            "llvm.x86.avx2.packsswb" => "__builtin_ia32_packsswb256",
-            "llvm.x86.avx2.packssdw" => "__builtin_ia32_packssdw256",
-            "llvm.x86.avx2.packuswb" => "__builtin_ia32_packuswb256",
-            "llvm.x86.avx2.packusdw" => "__builtin_ia32_packusdw256",
-            "llvm.x86.avx2.permd" => "__builtin_ia32_permvarsi256",
-            "llvm.x86.avx2.permps" => "__builtin_ia32_permvarsf256",
-            "llvm.x86.avx2.psad.bw" => "__builtin_ia32_psadbw256",
-            "llvm.x86.avx2.psign.w" => "__builtin_ia32_psignw256",
-            "llvm.x86.avx2.psign.d" => "__builtin_ia32_psignd256",
-            "llvm.x86.avx2.psign.b" => "__builtin_ia32_psignb256",
-            "llvm.x86.avx2.psll.w" => "__builtin_ia32_psllw256",
-            "llvm.x86.avx2.psll.d" => "__builtin_ia32_pslld256",
-            "llvm.x86.avx2.psll.q" => "__builtin_ia32_psllq256",
-            "llvm.x86.avx2.pslli.w" => "__builtin_ia32_psllwi256",
-            "llvm.x86.avx2.pslli.q" => "__builtin_ia32_psllqi256",
-            "llvm.x86.avx2.psllv.d" => "__builtin_ia32_psllv4si",
-            "llvm.x86.avx2.psllv.d.256" => "__builtin_ia32_psllv8si",
-            "llvm.x86.avx2.psllv.q" => "__builtin_ia32_psllv2di",
-            "llvm.x86.avx2.psllv.q.256" => "__builtin_ia32_psllv4di",
-            "llvm.x86.avx2.psra.w" => "__builtin_ia32_psraw256",
-            "llvm.x86.avx2.psra.d" => "__builtin_ia32_psrad256",
-            "llvm.x86.avx2.psrai.w" => "__builtin_ia32_psrawi256",
-            "llvm.x86.avx2.psrai.d" => "__builtin_ia32_psradi256",
-            "llvm.x86.avx2.psrav.d" => "__builtin_ia32_psrav4si",
-            "llvm.x86.avx2.psrav.d.256" => "__builtin_ia32_psrav8si",
-            "llvm.x86.avx2.psrl.w" => "__builtin_ia32_psrlw256",
-            "llvm.x86.avx2.psrl.d" => "__builtin_ia32_psrld256",
-            "llvm.x86.avx2.psrl.q" => "__builtin_ia32_psrlq256",
-            "llvm.x86.avx2.psrlv.d" => "__builtin_ia32_psrlv4si",
-            "llvm.x86.avx2.psrlv.d.256" => "__builtin_ia32_psrlv8si",
-            "llvm.x86.avx2.psrlv.q" => "__builtin_ia32_psrlv2di",
-            "llvm.x86.avx2.psrlv.q.256" => "__builtin_ia32_psrlv4di",
             "llvm.x86.sse.sqrt.ss" => "__builtin_ia32_sqrtss",
-            "llvm.x86.pclmulqdq" => "__builtin_ia32_pclmulqdq128",
-            "llvm.x86.sha1msg1" => "__builtin_ia32_sha1msg1",
-            "llvm.x86.sha1msg2" => "__builtin_ia32_sha1msg2",
-            "llvm.x86.sha1nexte" => "__builtin_ia32_sha1nexte",
-            "llvm.x86.sha1rnds4" => "__builtin_ia32_sha1rnds4",
-            "llvm.x86.sha256msg1" => "__builtin_ia32_sha256msg1",
-            "llvm.x86.sha256msg2" => "__builtin_ia32_sha256msg2",
-            "llvm.x86.sha256rnds2" => "__builtin_ia32_sha256rnds2",
 
-            "llvm.sqrt.v2f64" => "__builtin_ia32_sqrtpd",
-            _ => unimplemented!("***** unsupported LLVM intrinsic {}", name),
+            // The GCC builtins of the following LLVM intrinsics have different names (or LLVM does
+            // not specify them), so they are not in archs.rs.
+            "llvm.x86.avx2.gather.d.d" => "__builtin_ia32_gathersiv4si",
+            "llvm.x86.avx2.gather.d.d.256" => "__builtin_ia32_gathersiv8si",
+            "llvm.x86.avx2.gather.d.ps" => "__builtin_ia32_gathersiv4sf",
+            "llvm.x86.avx2.gather.d.ps.256" => "__builtin_ia32_gathersiv8sf",
+            "llvm.x86.avx2.gather.d.q" => "__builtin_ia32_gathersiv2di",
+            "llvm.x86.avx2.gather.d.q.256" => "__builtin_ia32_gathersiv4di",
+            "llvm.x86.avx2.gather.d.pd" => "__builtin_ia32_gathersiv2df",
+            "llvm.x86.avx2.gather.d.pd.256" => "__builtin_ia32_gathersiv4df",
+            "llvm.x86.avx2.gather.q.d" => "__builtin_ia32_gatherdiv4si",
+            "llvm.x86.avx2.gather.q.d.256" => "__builtin_ia32_gatherdiv4si256",
+            "llvm.x86.avx2.gather.q.ps" => "__builtin_ia32_gatherdiv4sf",
+            "llvm.x86.avx2.gather.q.ps.256" => "__builtin_ia32_gatherdiv4sf256",
+            "llvm.x86.avx2.gather.q.q" => "__builtin_ia32_gatherdiv2di",
+            "llvm.x86.avx2.gather.q.q.256" => "__builtin_ia32_gatherdiv4di",
+            "llvm.x86.avx2.gather.q.pd" => "__builtin_ia32_gatherdiv2df",
+            "llvm.x86.avx2.gather.q.pd.256" => "__builtin_ia32_gatherdiv4df",
+            "llvm.x86.avx512.gather.dps.512" => "__builtin_ia32_gathersiv16sf",
+            "llvm.x86.avx512.gather.dpd.512" => "__builtin_ia32_gathersiv8df",
+            "llvm.x86.avx512.gather.dpi.512" => "__builtin_ia32_gathersiv16si",
+            "llvm.x86.avx512.gather.dpq.512" => "__builtin_ia32_gathersiv8di",
+            "llvm.x86.avx512.gather.qps.512" => "__builtin_ia32_gatherdiv16sf",
+            "llvm.x86.avx512.gather.qpd.512" => "__builtin_ia32_gatherdiv8df",
+            "llvm.x86.avx512.gather.qpi.512" => "__builtin_ia32_gatherdiv16si",
+            "llvm.x86.avx512.gather.qpq.512" => "__builtin_ia32_gatherdiv8di",
+            "llvm.x86.avx512.gather3div2.df" => "__builtin_ia32_gather3div2df",
+            "llvm.x86.avx512.gather3div2.di" => "__builtin_ia32_gather3div2di",
+            "llvm.x86.avx512.gather3div4.df" => "__builtin_ia32_gather3div4df",
+            "llvm.x86.avx512.gather3div4.di" => "__builtin_ia32_gather3div4di",
+            "llvm.x86.avx512.gather3div4.sf" => "__builtin_ia32_gather3div4sf",
+            "llvm.x86.avx512.gather3div4.si" => "__builtin_ia32_gather3div4si",
+            "llvm.x86.avx512.gather3div8.sf" => "__builtin_ia32_gather3div8sf",
+            "llvm.x86.avx512.gather3div8.si" => "__builtin_ia32_gather3div8si",
+            "llvm.x86.avx512.gather3siv2.df" => "__builtin_ia32_gather3siv2df",
+            "llvm.x86.avx512.gather3siv2.di" => "__builtin_ia32_gather3siv2di",
+            "llvm.x86.avx512.gather3siv4.df" => "__builtin_ia32_gather3siv4df",
+            "llvm.x86.avx512.gather3siv4.di" => "__builtin_ia32_gather3siv4di",
+            "llvm.x86.avx512.gather3siv4.sf" => "__builtin_ia32_gather3siv4sf",
+            "llvm.x86.avx512.gather3siv4.si" => "__builtin_ia32_gather3siv4si",
+            "llvm.x86.avx512.gather3siv8.sf" => "__builtin_ia32_gather3siv8sf",
+            "llvm.x86.avx512.gather3siv8.si" => "__builtin_ia32_gather3siv8si",
+            "llvm.x86.avx512.scatter.dps.512" => "__builtin_ia32_scattersiv16sf",
+            "llvm.x86.avx512.scatter.dpd.512" => "__builtin_ia32_scattersiv8df",
+            "llvm.x86.avx512.scatter.dpi.512" => "__builtin_ia32_scattersiv16si",
+            "llvm.x86.avx512.scatter.dpq.512" => "__builtin_ia32_scattersiv8di",
+            "llvm.x86.avx512.scatter.qps.512" => "__builtin_ia32_scatterdiv16sf",
+            "llvm.x86.avx512.scatter.qpd.512" => "__builtin_ia32_scatterdiv8df",
+            "llvm.x86.avx512.scatter.qpi.512" => "__builtin_ia32_scatterdiv16si",
+            "llvm.x86.avx512.scatter.qpq.512" => "__builtin_ia32_scatterdiv8di",
+            "llvm.x86.avx512.scatterdiv2.df" => "__builtin_ia32_scatterdiv2df",
+            "llvm.x86.avx512.scatterdiv2.di" => "__builtin_ia32_scatterdiv2di",
+            "llvm.x86.avx512.scatterdiv4.df" => "__builtin_ia32_scatterdiv4df",
+            "llvm.x86.avx512.scatterdiv4.di" => "__builtin_ia32_scatterdiv4di",
+            "llvm.x86.avx512.scatterdiv4.sf" => "__builtin_ia32_scatterdiv4sf",
+            "llvm.x86.avx512.scatterdiv4.si" => "__builtin_ia32_scatterdiv4si",
+            "llvm.x86.avx512.scatterdiv8.sf" => "__builtin_ia32_scatterdiv8sf",
+            "llvm.x86.avx512.scatterdiv8.si" => "__builtin_ia32_scatterdiv8si",
+            "llvm.x86.avx512.scattersiv2.df" => "__builtin_ia32_scattersiv2df",
+            "llvm.x86.avx512.scattersiv2.di" => "__builtin_ia32_scattersiv2di",
+            "llvm.x86.avx512.scattersiv4.df" => "__builtin_ia32_scattersiv4df",
+            "llvm.x86.avx512.scattersiv4.di" => "__builtin_ia32_scattersiv4di",
+            "llvm.x86.avx512.scattersiv4.sf" => "__builtin_ia32_scattersiv4sf",
+            "llvm.x86.avx512.scattersiv4.si" => "__builtin_ia32_scattersiv4si",
+            "llvm.x86.avx512.scattersiv8.sf" => "__builtin_ia32_scattersiv8sf",
+            "llvm.x86.avx512.scattersiv8.si" => "__builtin_ia32_scattersiv8si",
+            "llvm.x86.avx512.mask.cmp.ps.128" => "__builtin_ia32_cmpps128_mask",
+            "llvm.x86.avx512.mask.cmp.ps.256" => "__builtin_ia32_cmpps256_mask",
+            "llvm.x86.avx512.mask.cmp.ps.512" => "__builtin_ia32_cmpps512_mask",
+            "llvm.x86.avx512.mask.cmp.pd.128" => "__builtin_ia32_cmppd128_mask",
+            "llvm.x86.avx512.mask.cmp.pd.256" => "__builtin_ia32_cmppd256_mask",
+            "llvm.x86.avx512.mask.cmp.pd.512" => "__builtin_ia32_cmppd512_mask",
+            "llvm.x86.avx512.mask.compress.b.128" => "__builtin_ia32_compressqi128_mask",
+            "llvm.x86.avx512.mask.compress.b.256" => "__builtin_ia32_compressqi256_mask",
+            "llvm.x86.avx512.mask.compress.b.512" => "__builtin_ia32_compressqi512_mask",
+            "llvm.x86.avx512.mask.compress.w.128" => "__builtin_ia32_compresshi128_mask",
+            "llvm.x86.avx512.mask.compress.w.256" => "__builtin_ia32_compresshi256_mask",
+            "llvm.x86.avx512.mask.compress.w.512" => "__builtin_ia32_compresshi512_mask",
+            "llvm.x86.avx512.mask.compress.d.128" => "__builtin_ia32_compresssi128_mask",
+            "llvm.x86.avx512.mask.compress.d.256" => "__builtin_ia32_compresssi256_mask",
+            "llvm.x86.avx512.mask.compress.d.512" => "__builtin_ia32_compresssi512_mask",
+            "llvm.x86.avx512.mask.compress.q.128" => "__builtin_ia32_compressdi128_mask",
+            "llvm.x86.avx512.mask.compress.q.256" => "__builtin_ia32_compressdi256_mask",
+            "llvm.x86.avx512.mask.compress.q.512" => "__builtin_ia32_compressdi512_mask",
+            "llvm.x86.avx512.mask.compress.ps.128" => "__builtin_ia32_compresssf128_mask",
+            "llvm.x86.avx512.mask.compress.ps.256" => "__builtin_ia32_compresssf256_mask",
+            "llvm.x86.avx512.mask.compress.ps.512" => "__builtin_ia32_compresssf512_mask",
+            "llvm.x86.avx512.mask.compress.pd.128" => "__builtin_ia32_compressdf128_mask",
+            "llvm.x86.avx512.mask.compress.pd.256" => "__builtin_ia32_compressdf256_mask",
+            "llvm.x86.avx512.mask.compress.pd.512" => "__builtin_ia32_compressdf512_mask",
+            "llvm.x86.avx512.mask.compress.store.b.128" => "__builtin_ia32_compressstoreuqi128_mask",
+            "llvm.x86.avx512.mask.compress.store.b.256" => "__builtin_ia32_compressstoreuqi256_mask",
+            "llvm.x86.avx512.mask.compress.store.b.512" => "__builtin_ia32_compressstoreuqi512_mask",
+            "llvm.x86.avx512.mask.compress.store.w.128" => "__builtin_ia32_compressstoreuhi128_mask",
+            "llvm.x86.avx512.mask.compress.store.w.256" => "__builtin_ia32_compressstoreuhi256_mask",
+            "llvm.x86.avx512.mask.compress.store.w.512" => "__builtin_ia32_compressstoreuhi512_mask",
+            "llvm.x86.avx512.mask.compress.store.d.128" => "__builtin_ia32_compressstoresi128_mask",
+            "llvm.x86.avx512.mask.compress.store.d.256" => "__builtin_ia32_compressstoresi256_mask",
+            "llvm.x86.avx512.mask.compress.store.d.512" => "__builtin_ia32_compressstoresi512_mask",
+            "llvm.x86.avx512.mask.compress.store.q.128" => "__builtin_ia32_compressstoredi128_mask",
+            "llvm.x86.avx512.mask.compress.store.q.256" => "__builtin_ia32_compressstoredi256_mask",
+            "llvm.x86.avx512.mask.compress.store.q.512" => "__builtin_ia32_compressstoredi512_mask",
+            "llvm.x86.avx512.mask.compress.store.ps.128" => "__builtin_ia32_compressstoresf128_mask",
+            "llvm.x86.avx512.mask.compress.store.ps.256" => "__builtin_ia32_compressstoresf256_mask",
+            "llvm.x86.avx512.mask.compress.store.ps.512" => "__builtin_ia32_compressstoresf512_mask",
+            "llvm.x86.avx512.mask.compress.store.pd.128" => "__builtin_ia32_compressstoredf128_mask",
+            "llvm.x86.avx512.mask.compress.store.pd.256" => "__builtin_ia32_compressstoredf256_mask",
+            "llvm.x86.avx512.mask.compress.store.pd.512" => "__builtin_ia32_compressstoredf512_mask",
+            "llvm.x86.avx512.mask.expand.b.128" => "__builtin_ia32_expandqi128_mask",
+            "llvm.x86.avx512.mask.expand.b.256" => "__builtin_ia32_expandqi256_mask",
+            "llvm.x86.avx512.mask.expand.b.512" => "__builtin_ia32_expandqi512_mask",
+            "llvm.x86.avx512.mask.expand.w.128" => "__builtin_ia32_expandhi128_mask",
+            "llvm.x86.avx512.mask.expand.w.256" => "__builtin_ia32_expandhi256_mask",
+            "llvm.x86.avx512.mask.expand.w.512" => "__builtin_ia32_expandhi512_mask",
+            "llvm.x86.avx512.mask.expand.d.128" => "__builtin_ia32_expandsi128_mask",
+            "llvm.x86.avx512.mask.expand.d.256" => "__builtin_ia32_expandsi256_mask",
+            "llvm.x86.avx512.mask.expand.d.512" => "__builtin_ia32_expandsi512_mask",
+            "llvm.x86.avx512.mask.expand.q.128" => "__builtin_ia32_expanddi128_mask",
+            "llvm.x86.avx512.mask.expand.q.256" => "__builtin_ia32_expanddi256_mask",
+            "llvm.x86.avx512.mask.expand.q.512" => "__builtin_ia32_expanddi512_mask",
+            "llvm.x86.avx512.mask.expand.ps.128" => "__builtin_ia32_expandsf128_mask",
+            "llvm.x86.avx512.mask.expand.ps.256" => "__builtin_ia32_expandsf256_mask",
+            "llvm.x86.avx512.mask.expand.ps.512" => "__builtin_ia32_expandsf512_mask",
+            "llvm.x86.avx512.mask.expand.pd.128" => "__builtin_ia32_expanddf128_mask",
+            "llvm.x86.avx512.mask.expand.pd.256" => "__builtin_ia32_expanddf256_mask",
+            "llvm.x86.avx512.mask.expand.pd.512" => "__builtin_ia32_expanddf512_mask",
+            "llvm.x86.avx512.mask.expand.load.b.128" => "__builtin_ia32_expandloadqi128_mask",
+            "llvm.x86.avx512.mask.expand.load.b.256" => "__builtin_ia32_expandloadqi256_mask",
+            "llvm.x86.avx512.mask.expand.load.b.512" => "__builtin_ia32_expandloadqi512_mask",
+            "llvm.x86.avx512.mask.expand.load.w.128" => "__builtin_ia32_expandloadhi128_mask",
+            "llvm.x86.avx512.mask.expand.load.w.256" => "__builtin_ia32_expandloadhi256_mask",
+            "llvm.x86.avx512.mask.expand.load.w.512" => "__builtin_ia32_expandloadhi512_mask",
+            "llvm.x86.avx512.mask.expand.load.d.128" => "__builtin_ia32_expandloadsi128_mask",
+            "llvm.x86.avx512.mask.expand.load.d.256" => "__builtin_ia32_expandloadsi256_mask",
+            "llvm.x86.avx512.mask.expand.load.d.512" => "__builtin_ia32_expandloadsi512_mask",
+            "llvm.x86.avx512.mask.expand.load.q.128" => "__builtin_ia32_expandloaddi128_mask",
+            "llvm.x86.avx512.mask.expand.load.q.256" => "__builtin_ia32_expandloaddi256_mask",
+            "llvm.x86.avx512.mask.expand.load.q.512" => "__builtin_ia32_expandloaddi512_mask",
+            "llvm.x86.avx512.mask.expand.load.ps.128" => "__builtin_ia32_expandloadsf128_mask",
+            "llvm.x86.avx512.mask.expand.load.ps.256" => "__builtin_ia32_expandloadsf256_mask",
+            "llvm.x86.avx512.mask.expand.load.ps.512" => "__builtin_ia32_expandloadsf512_mask",
+            "llvm.x86.avx512.mask.expand.load.pd.128" => "__builtin_ia32_expandloaddf128_mask",
+            "llvm.x86.avx512.mask.expand.load.pd.256" => "__builtin_ia32_expandloaddf256_mask",
+            "llvm.x86.avx512.mask.expand.load.pd.512" => "__builtin_ia32_expandloaddf512_mask",
+            "llvm.x86.avx512.fpclass.ps.128" => "__builtin_ia32_fpclassps128_mask",
+            "llvm.x86.avx512.fpclass.ps.256" => "__builtin_ia32_fpclassps256_mask",
+            "llvm.x86.avx512.fpclass.ps.512" => "__builtin_ia32_fpclassps512_mask",
+            "llvm.x86.avx512.fpclass.pd.128" => "__builtin_ia32_fpclasspd128_mask",
+            "llvm.x86.avx512.fpclass.pd.256" => "__builtin_ia32_fpclasspd256_mask",
+            "llvm.x86.avx512.fpclass.pd.512" => "__builtin_ia32_fpclasspd512_mask",
+            "llvm.x86.avx512.sqrt.ps.512" => "__builtin_ia32_sqrtps512_mask",
+            "llvm.x86.avx512.sqrt.pd.512" => "__builtin_ia32_sqrtpd512_mask",
+            "llvm.x86.avx512.vfmadd.ps.512" => "__builtin_ia32_vfmaddps512_mask",
+            "llvm.x86.avx512.vfmadd.pd.512" => "__builtin_ia32_vfmaddpd512_mask",
+            "llvm.x86.avx512.vfmaddsub.ps.512" => "__builtin_ia32_vfmaddsubps512_mask",
+            "llvm.x86.avx512.vfmaddsub.pd.512" => "__builtin_ia32_vfmaddsubpd512_mask",
+            "llvm.x86.avx512.vfmadd.f32" => "__builtin_ia32_vfmaddss3_round",
+            "llvm.x86.avx512.vfmadd.f64" => "__builtin_ia32_vfmaddsd3_round",
+            "llvm.x86.avx512.sitofp.round.v16f32.v16i32" => "__builtin_ia32_cvtdq2ps512_mask",
+            "llvm.x86.avx512.uitofp.round.v16f32.v16i32" => "__builtin_ia32_cvtudq2ps512_mask",
+            "llvm.x86.avx512.sitofp.round.v8f64.v8i64" => "__builtin_ia32_cvtqq2pd512_mask",
+            "llvm.x86.avx512.uitofp.round.v8f64.v8i64" => "__builtin_ia32_cvtuqq2pd512_mask",
+            "llvm.x86.avx512.sitofp.round.v8f32.v8i64" => "__builtin_ia32_cvtqq2ps512_mask",
+            "llvm.x86.avx512.uitofp.round.v8f32.v8i64" => "__builtin_ia32_cvtuqq2ps512_mask",
+            "llvm.x86.avx512.mask.vcvtph2ps.512" => "__builtin_ia32_vcvtph2ps512_mask",
+            "llvm.x86.avx512.vpshufbitqmb.128" => "__builtin_ia32_vpshufbitqmb128_mask",
+            "llvm.x86.avx512.vpshufbitqmb.256" => "__builtin_ia32_vpshufbitqmb256_mask",
+            "llvm.x86.avx512.vpshufbitqmb.512" => "__builtin_ia32_vpshufbitqmb512_mask",
+            "llvm.x86.avx512.vpdpbusd.128" => "__builtin_ia32_vpdpbusd_v4si",
+            "llvm.x86.avx512.vpdpbusd.256" => "__builtin_ia32_vpdpbusd_v8si",
+            "llvm.x86.avx512.vpdpbusd.512" => "__builtin_ia32_vpdpbusd_v16si",
+            "llvm.x86.avx512.vpdpbusds.128" => "__builtin_ia32_vpdpbusds_v4si",
+            "llvm.x86.avx512.vpdpbusds.256" => "__builtin_ia32_vpdpbusds_v8si",
+            "llvm.x86.avx512.vpdpbusds.512" => "__builtin_ia32_vpdpbusds_v16si",
+            "llvm.x86.avx512.vpdpwssd.128" => "__builtin_ia32_vpdpwssd_v4si",
+            "llvm.x86.avx512.vpdpwssd.256" => "__builtin_ia32_vpdpwssd_v8si",
+            "llvm.x86.avx512.vpdpwssd.512" => "__builtin_ia32_vpdpwssd_v16si",
+            "llvm.x86.avx512.vpdpwssds.128" => "__builtin_ia32_vpdpwssds_v4si",
+            "llvm.x86.avx512.vpdpwssds.256" => "__builtin_ia32_vpdpwssds_v8si",
+            "llvm.x86.avx512.vpdpwssds.512" => "__builtin_ia32_vpdpwssds_v16si",
+            "llvm.x86.avx512bf16.cvtne2ps2bf16.128" => "__builtin_ia32_cvtne2ps2bf16_v8hi",
+            "llvm.x86.avx512bf16.cvtne2ps2bf16.256" => "__builtin_ia32_cvtne2ps2bf16_v16hi",
+            "llvm.x86.avx512bf16.cvtne2ps2bf16.512" => "__builtin_ia32_cvtne2ps2bf16_v32hi",
+            "llvm.x86.avx512bf16.mask.cvtneps2bf16.128" => "__builtin_ia32_cvtneps2bf16_v4sf_mask",
+            "llvm.x86.avx512bf16.cvtneps2bf16.256" => "__builtin_ia32_cvtneps2bf16_v8sf",
+            "llvm.x86.avx512bf16.cvtneps2bf16.512" => "__builtin_ia32_cvtneps2bf16_v16sf",
+            "llvm.x86.avx512bf16.dpbf16ps.128" => "__builtin_ia32_dpbf16ps_v4sf",
+            "llvm.x86.avx512bf16.dpbf16ps.256" => "__builtin_ia32_dpbf16ps_v8sf",
+            "llvm.x86.avx512bf16.dpbf16ps.512" => "__builtin_ia32_dpbf16ps_v16sf",
+            "llvm.x86.aesni.aesdec.256" => "__builtin_ia32_vaesdec_v32qi",
+            "llvm.x86.aesni.aesdec.512" => "__builtin_ia32_vaesdec_v64qi",
+            "llvm.x86.aesni.aesdeclast.256" => "__builtin_ia32_vaesdeclast_v32qi",
+            "llvm.x86.aesni.aesdeclast.512" => "__builtin_ia32_vaesdeclast_v64qi",
+            "llvm.x86.aesni.aesenc.256" => "__builtin_ia32_vaesenc_v32qi",
+            "llvm.x86.aesni.aesenc.512" => "__builtin_ia32_vaesenc_v64qi",
+            "llvm.x86.aesni.aesenclast.256" => "__builtin_ia32_vaesenclast_v32qi",
+            "llvm.x86.aesni.aesenclast.512" => "__builtin_ia32_vaesenclast_v64qi",
+            "llvm.x86.pclmulqdq.256" => "__builtin_ia32_vpclmulqdq_v4di",
+            "llvm.x86.pclmulqdq.512" => "__builtin_ia32_vpclmulqdq_v8di",
+
+            _ => archs::llvm_to_gcc_builtin(name).unwrap_or_else(|| unsupported_intrinsic(cx, name)),
         };
 
+    builtin_function(cx, gcc_name)
+}
+
+fn builtin_function<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, gcc_name: &str) -> Function<'gcc> {
     let func = cx.context.get_target_builtin_function(gcc_name);
     cx.functions.borrow_mut().insert(gcc_name.to_string(), func);
+    cx.intrinsic_builtins.borrow_mut().insert(func, gcc_name.to_string());
     func
 }
+
+fn unsupported_intrinsic(cx: &CodegenCx<'_, '_>, name: &str) -> ! {
+    cx.sess().fatal(&format!("the LLVM intrinsic `{}` is not supported by the GCC backend", name))
+}
+
+/// Returns the name of the GCC builtin equivalent to the AArch64 LLVM intrinsic `name`, if any.
+// NOTE: unlike for x86, LLVM does not specify the GCC builtins of the AArch64 intrinsics, so they
+// are mapped by hand. The NEON intrinsics are overloaded: their types are at the end of their name
+// (e.g. `llvm.aarch64.neon.umaxv.i8.v16i8`) and the type of the vector argument gives the mode of
+// the GCC builtin (e.g. `__builtin_aarch64_reduc_umax_scal_v16qi_uu`). The suffix of the GCC
+// builtins gives the signedness of their result and arguments when some are unsigned.
+fn aarch64_builtin(name: &str) -> Option<String> {
+    let name = name.strip_prefix("llvm.aarch64.")?;
+    let builtin =
+        match name {
+            "crc32b" => "crc32b",
+            "crc32h" => "crc32h",
+            "crc32w" => "crc32w",
+            "crc32x" => "crc32x",
+            "crc32cb" => "crc32cb",
+            "crc32ch" => "crc32ch",
+            "crc32cw" => "crc32cw",
+            "crc32cx" => "crc32cx",
+            "crypto.aese" => "crypto_aesev16qi_uuu",
+            "crypto.aesd" => "crypto_aesdv16qi_uuu",
+            "crypto.aesmc" => "crypto_aesmcv16qi_uu",
+            "crypto.aesimc" => "crypto_aesimcv16qi_uu",
+            "crypto.sha1c" => "crypto_sha1cv4si_uuuu",
+            "crypto.sha1m" => "crypto_sha1mv4si_uuuu",
+            "crypto.sha1p" => "crypto_sha1pv4si_uuuu",
+            "crypto.sha1h" => "crypto_sha1hsi_uu",
+            "crypto.sha1su0" => "crypto_sha1su0v4si_uuuu",
+            "crypto.sha1su1" => "crypto_sha1su1v4si_uuu",
+            "crypto.sha256h" => "crypto_sha256hv4si_uuuu",
+            "crypto.sha256h2" => "crypto_sha256h2v4si_uuuu",
+            "crypto.sha256su0" => "crypto_sha256su0v4si_uuu",
+            "crypto.sha256su1" => "crypto_sha256su1v4si_uuuu",
+            "crypto.sha512h" => "crypto_sha512hqv2di_uuuu",
+            "crypto.sha512h2" => "crypto_sha512h2qv2di_uuuu",
+            "crypto.sha512su0" => "crypto_sha512su0qv2di_uuu",
+            "crypto.sha512su1" => "crypto_sha512su1qv2di_uuuu",
+            "neon.pmull64" => "crypto_pmulldi_ppp",
+            "neon.pmull.v8i16" => "pmullv8qi",
+            _ => {
+                let mut parts = name.strip_prefix("neon.")?.split('.');
+                let op = parts.next()?;
+                let llvm_type = parts.last()?;
+                let mode = gcc_mode(llvm_type)?;
+                let builtin =
+                    match op {
+                        "abs" => format!("abs{}", mode),
+                        "addp" => format!("addp{}", mode),
+                        "cls" => format!("clrsb{}", mode),
+                        "fabd" => format!("fabd{}", mode),
+                        "faddp" => format!("faddp{}", mode),
+                        "faddv" => format!("reduc_plus_scal_{}", mode),
+                        "fmax" => format!("smax_nan{}", mode),
+                        "fmaxnm" => format!("fmax{}", mode),
+                        "fmaxnmp" => format!("smaxp{}", mode),
+                        "fmaxnmv" => format!("reduc_smax_scal_{}", mode),
+                        "fmaxp" => format!("smax_nanp{}", mode),
+                        "fmaxv" => format!("reduc_smax_nan_scal_{}", mode),
+                        "fmin" => format!("smin_nan{}", mode),
+                        "fminnm" => format!("fmin{}", mode),
+                        "fminnmp" => format!("sminp{}", mode),
+                        "fminnmv" => format!("reduc_smin_scal_{}", mode),
+                        "fminp" => format!("smin_nanp{}", mode),
+                        "fminv" => format!("reduc_smin_nan_scal_{}", mode),
+                        "fmulx" => format!("fmulx{}", mode),
+                        "frecpe" => format!("frecpe{}", mode),
+                        "frecps" => format!("frecps{}", mode),
+                        "frint32x" => format!("frint32x{}", mode),
+                        "frint32z" => format!("frint32z{}", mode),
+                        "frint64x" => format!("frint64x{}", mode),
+                        "frint64z" => format!("frint64z{}", mode),
+                        "frintn" => format!("frintn{}", mode),
+                        "frsqrte" => format!("rsqrte{}", mode),
+                        "frsqrts" => format!("rsqrts{}", mode),
+                        "pmul" => format!("pmul{}", mode),
+                        "rbit" => format!("rbit{}", mode),
+                        "sabd" => format!("sabd{}", mode),
+                        "saddlp" => format!("saddlp{}", mode),
+                        // NOTE: the result is widened like for uaddlv.
+                        "saddlv" => format!("saddlv{}", mode),
+                        "saddv" | "uaddv" => format!("reduc_plus_scal_{}", mode),
+                        "shadd" => format!("shadd{}", mode),
+                        "shsub" => format!("shsub{}", mode),
+                        "smaxp" => format!("smaxp{}", mode),
+                        "smaxv" => format!("reduc_smax_scal_{}", mode),
+                        "sminp" => format!("sminp{}", mode),
+                        "sminv" => format!("reduc_smin_scal_{}", mode),
+                        "sqabs" => format!("sqabs{}", mode),
+                        "sqadd" => format!("sqadd{}", mode),
+                        "sqdmulh" => format!("sqdmulh{}", mode),
+                        "sqneg" => format!("sqneg{}", mode),
+                        "sqrdmulh" => format!("sqrdmulh{}", mode),
+                        "sqrshl" => format!("sqrshl{}", mode),
+                        "sqshl" => format!("sqshl{}", mode),
+                        "sqsub" => format!("sqsub{}", mode),
+                        // NOTE: the narrowing intrinsics are overloaded on their result type, while
+                        // the mode of the GCC builtins is the one of their argument.
+                        "sqxtn" => format!("sqmovn{}", double_width_mode(mode)?),
+                        "sqxtun" => format!("sqmovun{}_us", double_width_mode(mode)?),
+                        "srhadd" => format!("srhadd{}", mode),
+                        "srshl" => format!("srshl{}", mode),
+                        "sshl" => format!("sshl{}", mode),
+                        "tbl1" => format!("qtbl1{}", mode),
+                        "tbx1" => format!("qtbx1{}", mode),
+                        "uabd" => format!("uabd{}_uuu", mode),
+                        "uaddlp" => format!("uaddlp{}_uu", mode),
+                        // NOTE: the result is widened to the return type of the LLVM intrinsic in
+                        // adjust_intrinsic_return_value().
+                        "uaddlv" => format!("uaddlv{}_uu", mode),
+                        "uhadd" => format!("uhadd{}_uuu", mode),
+                        "uhsub" => format!("uhsub{}_uuu", mode),
+                        "umaxp" => format!("umaxp{}_uuu", mode),
+                        "umaxv" => format!("reduc_umax_scal_{}_uu", mode),
+                        "uminp" => format!("uminp{}_uuu", mode),
+                        "uminv" => format!("reduc_umin_scal_{}_uu", mode),
+                        "uqadd" => format!("uqadd{}_uuu", mode),
+                        "uqrshl" => format!("uqrshl{}_uus", mode),
+                        "uqshl" => format!("uqshl{}_uus", mode),
+                        "uqsub" => format!("uqsub{}_uuu", mode),
+                        "uqxtn" => format!("uqmovn{}_uu", double_width_mode(mode)?),
+                        "urecpe" => format!("urecpe{}_uu", mode),
+                        "urhadd" => format!("urhadd{}_uuu", mode),
+                        "urshl" => format!("urshl{}_uus", mode),
+                        "ursqrte" => format!("ursqrte{}_uu", mode),
+                        "ushl" => format!("ushl{}_uus", mode),
+                        _ => return None,
+                    };
+                return Some(format!("__builtin_aarch64_{}", builtin));
+            },
+        };
+    Some(format!("__builtin_aarch64_{}", builtin))
+}
+
+/// Returns the GCC machine mode of the LLVM type `llvm_type`.
+fn gcc_mode(llvm_type: &str) -> Option<&'static str> {
+    let mode =
+        match llvm_type {
+            "i8" => "qi",
+            "i16" => "hi",
+            "i32" => "si",
+            "i64" | "v1i64" => "di",
+            "f32" => "sf",
+            "f64" | "v1f64" => "df",
+            "v8i8" => "v8qi",
+            "v16i8" => "v16qi",
+            "v4i16" => "v4hi",
+            "v8i16" => "v8hi",
+            "v2i32" => "v2si",
+            "v4i32" => "v4si",
+            "v2i64" => "v2di",
+            "v2f32" => "v2sf",
+            "v4f32" => "v4sf",
+            "v2f64" => "v2df",
+            _ => return None,
+        };
+    Some(mode)
+}
+
+/// Returns the mode of the vector having the same number of elements as the vector of mode `mode`,
+/// with elements twice as wide.
+fn double_width_mode(mode: &str) -> Option<&'static str> {
+    let mode =
+        match mode {
+            "v8qi" => "v8hi",
+            "v4hi" => "v4si",
+            "v2si" => "v2di",
+            _ => return None,
+        };
+    Some(mode)
+}
@@ -1,7 +1,8 @@
 pub mod llvm;
+mod archs;
 mod simd;
 
-use gccjit::{ComparisonOp, Function, RValue, ToRValue, Type, UnaryOp, FunctionType};
+use gccjit::{ComparisonOp, Function, RValue, ToRValue, Type, UnaryOp};
 use rustc_codegen_ssa::MemFlags;
 use rustc_codegen_ssa::base::wants_msvc_seh;
 use rustc_codegen_ssa::common::{IntPredicate, span_invalid_monomorphization_error};
@@ -133,8 +134,11 @@ impl<'a, 'gcc, 'tcx> IntrinsicCallMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
                     if let PassMode::Cast(ty) = fn_abi.ret.mode {
                         ptr = self.pointercast(ptr, self.type_ptr_to(ty.gcc_type(self)));
                     }
+                    if name == sym::unaligned_volatile_load {
+                        let value_type = ptr.dereference(None).to_rvalue().get_type().unqualified();
+                        ptr = self.pointercast(ptr, value_type.get_aligned(1).make_pointer());
+                    }
                     let load = self.volatile_load(ptr.get_type(), ptr);
-                    // TODO(antoyo): set alignment.
                     self.to_immediate(load, self.layout_of(tp_ty))
                 }
                 sym::volatile_store => {
@@ -340,15 +344,29 @@ impl<'a, 'gcc, 'tcx> IntrinsicCallMethods<'tcx> for Builder<'a, 'gcc, 'tcx> {
     }
 
     fn assume(&mut self, value: Self::Value) {
-        // TODO(antoyo): switch to asumme when it exists.
-        // Or use something like this:
-        // #define __assume(cond) do { if (!(cond)) __builtin_unreachable(); } while (0)
-        self.expect(value, true);
+        // NOTE: GCC does not have an assume builtin, so use the equivalent of:
+        // if (!(cond)) __builtin_unreachable();
+        let func = self.current_func();
+        let unreachable_block = func.new_block("assume_unreachable");
+        let after_block = func.new_block("assume_after");
+        self.llbb().end_with_conditional(None, value, after_block, unreachable_block);
+
+        let builtin_unreachable = self.context.get_builtin_function("__builtin_unreachable");
+        unreachable_block.add_eval(None, self.context.new_call(None, builtin_unreachable, &[]));
+        unreachable_block.end_with_jump(None, after_block);
+
+        self.switch_to_block(after_block);
     }
 
-    fn expect(&mut self, cond: Self::Value, _expected: bool) -> Self::Value {
-        // TODO(antoyo)
-        cond
+    fn expect(&mut self, cond: Self::Value, expected: bool) -> Self::Value {
+        let cond_type = cond.get_type();
+        let builtin_expect = self.context.get_builtin_function("__builtin_expect");
+        let args = [
+            self.context.new_cast(None, cond, self.long_type),
+            self.context.new_rvalue_from_long(self.long_type, expected as i64),
+        ];
+        let result = self.context.new_call(None, builtin_expect, &args);
+        self.context.new_cast(None, result, cond_type)
     }
 
     fn type_test(&mut self, _pointer: Self::Value, _typeid: Self::Value) -> Self::Value {
@@ -967,11 +985,7 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
     }
 
     fn saturating_add(&mut self, lhs: RValue<'gcc>, rhs: RValue<'gcc>, signed: bool, width: u64) -> RValue<'gcc> {
-        let func = self.current_func.borrow().expect("func");
-
         if signed {
-            // Algorithm from: https://stackoverflow.com/a/56531252/389119
-            let after_block = func.new_block("after");
             let func_name =
                 match width {
                     8 => "__builtin_add_overflow",
@@ -981,107 +995,75 @@ impl<'a, 'gcc, 'tcx> Builder<'a, 'gcc, 'tcx> {
                     128 => "__builtin_add_overflow",
                     _ => unreachable!(),
                 };
-            let overflow_func = self.context.get_builtin_function(func_name);
-            let result_type = lhs.get_type();
-            let res = func.new_local(None, result_type, "saturating_sum");
-            let overflow = self.overflow_call(overflow_func, &[lhs, rhs, res.get_address(None)], None);
-
-            let then_block = func.new_block("then");
-
-            let unsigned_type = self.context.new_int_type(width as i32 / 8, false);
-            let shifted = self.context.new_cast(None, lhs, unsigned_type) >> self.context.new_rvalue_from_int(unsigned_type, width as i32 - 1);
-            let uint_max = self.context.new_unary_op(None, UnaryOp::BitwiseNegate, unsigned_type,
-                self.context.new_rvalue_from_int(unsigned_type, 0)
-            );
-            let int_max = uint_max >> self.context.new_rvalue_one(unsigned_type);
-            then_block.add_assignment(None, res, self.context.new_cast(None, shifted + int_max, result_type));
-            then_block.end_with_jump(None, after_block);
-
-            self.llbb().end_with_conditional(None, overflow, then_block, after_block);
-
-            // NOTE: since jumps were added in a place rustc does not
-            // expect, the current block in the state need to be updated.
-            self.switch_to_block(after_block);
-
-            res.to_rvalue()
+            self.signed_saturating_operation(lhs, rhs, width, func_name, "__rust_i128_addo", "saturating_sum")
         }
         else {
             // Algorithm from: http://locklessinc.com/articles/sat_arithmetic/
-            let res = lhs + rhs;
+            let res = self.gcc_add(lhs, rhs);
             let res_type = res.get_type();
-            let cond = self.context.new_comparison(None, ComparisonOp::LessThan, res, lhs);
-            let value = self.context.new_unary_op(None, UnaryOp::Minus, res_type, self.context.new_cast(None, cond, res_type));
-            res | value
+            let cond = self.gcc_icmp(IntPredicate::IntULT, res, lhs);
+            let value = self.gcc_neg(self.gcc_int_cast(cond, res_type));
+            self.gcc_or(res, value)
         }
     }
 
     // Algorithm from: https://locklessinc.com/articles/sat_arithmetic/
     fn saturating_sub(&mut self, lhs: RValue<'gcc>, rhs: RValue<'gcc>, signed: bool, width: u64) -> RValue<'gcc> {
         if signed {
-            // Also based on algorithm from: https://stackoverflow.com/a/56531252/389119
-            let result_type = lhs.get_type();
-            let func = self.current_func.borrow().expect("func");
-            let res = func.new_local(None, result_type, "saturating_diff");
-            let supports_native_type = self.is_native_int_type(result_type);
-            let overflow =
-                if supports_native_type {
-                    let func_name =
-                        match width {
-                            8 => "__builtin_sub_overflow",
-                            16 => "__builtin_sub_overflow",
-                            32 => "__builtin_ssub_overflow",
-                            64 => "__builtin_ssubll_overflow",
-                            128 => "__builtin_sub_overflow",
-                            _ => unreachable!(),
-                        };
-                    let overflow_func = self.context.get_builtin_function(func_name);
-                    self.overflow_call(overflow_func, &[lhs, rhs, res.get_address(None)], None)
-                }
-                else {
-                    let func_name =
-                        match width {
-                            128 => "__rust_i128_subo",
-                            _ => unreachable!(),
-                        };
-                    let param_a = self.context.new_parameter(None, result_type, "a");
-                    let param_b = self.context.new_parameter(None, result_type, "b");
-                    let result_field = self.context.new_field(None, result_type, "result");
-                    let overflow_field = self.context.new_field(None, self.bool_type, "overflow");
-                    let return_type = self.context.new_struct_type(None, "result_overflow", &[result_field, overflow_field]);
-                    let func = self.context.new_function(None, FunctionType::Extern, return_type.as_type(), &[param_a, param_b], func_name, false);
-                    let result = self.context.new_call(None, func, &[lhs, rhs]);
-                    let overflow = result.access_field(None, overflow_field);
-                    let int_result = result.access_field(None, result_field);
-                    self.llbb().add_assignment(None, res, int_result);
-                    overflow
+            let func_name =
+                match width {
+                    8 => "__builtin_sub_overflow",
+                    16 => "__builtin_sub_overflow",
+                    32 => "__builtin_ssub_overflow",
+                    64 => "__builtin_ssubll_overflow",
+                    128 => "__builtin_sub_overflow",
+                    _ => unreachable!(),
                 };
+            self.signed_saturating_operation(lhs, rhs, width, func_name, "__rust_i128_subo", "saturating_diff")
+        }
+        else {
+            let res = self.gcc_sub(lhs, rhs);
+            let comparison = self.gcc_icmp(IntPredicate::IntULE, res, lhs);
+            let comparison = self.gcc_int_cast(comparison, lhs.get_type());
+            let unary_op = self.gcc_neg(comparison);
+            self.and(res, unary_op)
+        }
+    }
 
-            let then_block = func.new_block("then");
-            let after_block = func.new_block("after");
+    // Based on algorithm from: https://stackoverflow.com/a/56531252/389119
+    fn signed_saturating_operation(&mut self, lhs: RValue<'gcc>, rhs: RValue<'gcc>, width: u64, builtin_name: &str, non_native_func_name: &str, result_name: &str) -> RValue<'gcc> {
+        let result_type = lhs.get_type();
+        let func = self.current_func.borrow().expect("func");
+        let res = func.new_local(None, result_type, result_name);
+        let overflow =
+            if self.is_native_int_type(result_type) {
+                let overflow_func = self.context.get_builtin_function(builtin_name);
+                self.overflow_call(overflow_func, &[lhs, rhs, res.get_address(None)], None)
+            }
+            else {
+                let (int_result, overflow) = self.non_native_overflow_call(non_native_func_name, lhs, rhs);
+                self.llbb().add_assignment(None, res, int_result);
+                overflow
+            };
 
-            // NOTE: convert the type to unsigned to have an unsigned shift.
-            let unsigned_type = result_type.to_unsigned(&self.cx);
-            let shifted = self.gcc_lshr(self.gcc_int_cast(lhs, unsigned_type), self.gcc_int(unsigned_type, width as i64 - 1));
-            let uint_max = self.gcc_not(self.gcc_int(unsigned_type, 0));
-            let int_max = self.gcc_lshr(uint_max, self.gcc_int(unsigned_type, 1));
-            then_block.add_assignment(None, res, self.gcc_int_cast(self.gcc_add(shifted, int_max), result_type));
-            then_block.end_with_jump(None, after_block);
+        let then_block = func.new_block("then");
+        let after_block = func.new_block("after");
 
-            self.llbb().end_with_conditional(None, overflow, then_block, after_block);
+        // NOTE: convert the type to unsigned to have an unsigned shift.
+        let unsigned_type = result_type.to_unsigned(&self.cx);
+        let shifted = self.gcc_lshr(self.gcc_int_cast(lhs, unsigned_type), self.gcc_int(unsigned_type, width as i64 - 1));
+        let uint_max = self.gcc_not(self.gcc_int(unsigned_type, 0));
+        let int_max = self.gcc_lshr(uint_max, self.gcc_int(unsigned_type, 1));
+        then_block.add_assignment(None, res, self.gcc_int_cast(self.gcc_add(shifted, int_max), result_type));
+        then_block.end_with_jump(None, after_block);
 
-            // NOTE: since jumps were added in a place rustc does not
-            // expect, the current block in the state need to be updated.
-            self.switch_to_block(after_block);
+        self.llbb().end_with_conditional(None, overflow, then_block, after_block);
 
-            res.to_rvalue()
-        }
-        else {
-            let res = lhs - rhs;
-            let comparison = self.context.new_comparison(None, ComparisonOp::LessThanEquals, res, lhs);
-            let comparison = self.context.new_cast(None, comparison, lhs.get_type());
-            let unary_op = self.context.new_unary_op(None, UnaryOp::Minus, comparison.get_type(), comparison);
-            self.and(res, unary_op)
-        }
+        // NOTE: since jumps were added in a place rustc does not
+        // expect, the current block in the state need to be updated.
+        self.switch_to_block(after_block);
+
+        res.to_rvalue()
     }
 }
 
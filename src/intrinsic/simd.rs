@@ -1,8 +1,6 @@
-use std::cmp::Ordering;
-
-use gccjit::{RValue, Type, ToRValue};
+use gccjit::{BinaryOp, ComparisonOp, RValue, ToRValue, Type, UnaryOp};
 use rustc_codegen_ssa::base::compare_simd_types;
-use rustc_codegen_ssa::common::{TypeKind, span_invalid_monomorphization_error};
+use rustc_codegen_ssa::common::{IntPredicate, TypeKind, span_invalid_monomorphization_error};
 use rustc_codegen_ssa::mir::operand::OperandRef;
 use rustc_codegen_ssa::traits::{BaseTypeMethods, BuilderMethods};
 use rustc_hir as hir;
@@ -12,6 +10,7 @@ use rustc_middle::ty::{self, Ty};
 use rustc_span::{Span, Symbol, sym};
 
 use crate::builder::Builder;
+use crate::common::TypeReflection;
 use crate::intrinsic;
 
 pub fn generic_simd_intrinsic<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>, name: Symbol, callee_ty: Ty<'tcx>, args: &[OperandRef<'tcx, RValue<'gcc>>], ret_ty: Ty<'tcx>, llret_ty: Type<'gcc>, span: Span) -> Result<RValue<'gcc>, ()> {
@@ -57,6 +56,38 @@ pub fn generic_simd_intrinsic<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>,
     let arg_tys = sig.inputs();
     let name_str = name.as_str();
 
+    if name == sym::simd_select_bitmask {
+        let mask_ty = arg_tys[0];
+        let mask_len = match mask_ty.kind() {
+            ty::Int(i) => i.bit_width().unwrap_or_else(|| bx.tcx().data_layout.pointer_size.bits()),
+            ty::Uint(i) => i.bit_width().unwrap_or_else(|| bx.tcx().data_layout.pointer_size.bits()),
+            _ => return_error!("`{}` is not an integral type", mask_ty),
+        };
+        require_simd!(arg_tys[1], "argument");
+        let (vector_len, _) = arg_tys[1].simd_size_and_type(bx.tcx());
+        require!(
+            // Allow masks for vectors with fewer than 8 elements to be
+            // represented with a u8 or i8.
+            mask_len == vector_len || (mask_len == 8 && vector_len < 8),
+            "mismatched lengths: mask length `{}` != other vector length `{}`",
+            mask_len,
+            vector_len
+        );
+
+        let mask = args[0].immediate();
+        let mask_type = mask.get_type();
+        let one = bx.context.new_rvalue_one(mask_type);
+        let lane_masks = (0..vector_len)
+            .map(|index| {
+                let shift = bx.context.new_rvalue_from_long(mask_type, index as i64);
+                let bit = bx.context.new_binary_op(None, BinaryOp::RShift, mask_type, mask, shift);
+                let bit = bx.context.new_binary_op(None, BinaryOp::BitwiseAnd, mask_type, bit, one);
+                bx.context.new_comparison(None, ComparisonOp::Equals, bit, one)
+            })
+            .collect::<Vec<_>>();
+        return Ok(select_lanes(bx, &lane_masks, args[1].immediate(), args[2].immediate()));
+    }
+
     // every intrinsic below takes a SIMD vector as its first argument
     require_simd!(arg_tys[0], "input");
     let in_ty = arg_tys[0];
@@ -168,6 +199,15 @@ pub fn generic_simd_intrinsic<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>,
         // TODO(antoyo): use a recursive unqualified() here.
         let vector_type = vector.get_type().unqualified().dyncast_vector().expect("vector type");
         let element_type = vector_type.get_element_type();
+        if !matches!(&*bx.tcx().sess.target.arch, "x86" | "x86_64") {
+            // NOTE: the elements of a vector are in the same order in memory on little-endian and
+            // big-endian targets, so setting the element of a local copy works on every target.
+            let result = bx.current_func().new_local(None, vector.get_type(), "vectorInsertResult");
+            bx.llbb().add_assignment(None, result, vector);
+            let element = bx.context.new_vector_access(None, result.to_rvalue(), index);
+            bx.llbb().add_assignment(None, element, value);
+            return Ok(result.to_rvalue());
+        }
         // NOTE: we cannot cast to an array and assign to its element here because the value might
         // not be an l-value. So, call a builtin to set the element.
         // TODO(antoyo): perhaps we could create a new vector or maybe there's a GIMPLE instruction for that?
@@ -225,7 +265,7 @@ pub fn generic_simd_intrinsic<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>,
         return Ok(bx.context.new_vector_access(None, vector, args[1].immediate()).to_rvalue());
     }
 
-    if name == sym::simd_cast {
+    if name == sym::simd_cast || name == sym::simd_as {
         require_simd!(ret_ty, "return");
         let (out_len, out_elem) = ret_ty.simd_size_and_type(bx.tcx());
         require!(
@@ -275,71 +315,15 @@ pub fn generic_simd_intrinsic<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>,
             _ => (Style::Unsupported, 0),
         };
 
-        let extend = |in_type, out_type| {
-            let vector_type = bx.context.new_vector_type(out_type, 8);
-            let vector = args[0].immediate();
-            let array_type = bx.context.new_array_type(None, in_type, 8);
-            // TODO(antoyo): switch to using new_vector_access or __builtin_convertvector for vector casting.
-            let array = bx.context.new_bitcast(None, vector, array_type);
-
-            let cast_vec_element = |index| {
-                let index = bx.context.new_rvalue_from_int(bx.int_type, index);
-                bx.context.new_cast(None, bx.context.new_array_access(None, array, index).to_rvalue(), out_type)
-            };
-
-            bx.context.new_rvalue_from_vector(None, vector_type, &[
-                cast_vec_element(0),
-                cast_vec_element(1),
-                cast_vec_element(2),
-                cast_vec_element(3),
-                cast_vec_element(4),
-                cast_vec_element(5),
-                cast_vec_element(6),
-                cast_vec_element(7),
-            ])
-        };
-
         match (in_style, out_style) {
-            (Style::Int(in_is_signed), Style::Int(_)) => {
-                return Ok(match in_width.cmp(&out_width) {
-                    Ordering::Greater => bx.trunc(args[0].immediate(), llret_ty),
-                    Ordering::Equal => args[0].immediate(),
-                    Ordering::Less => {
-                        if in_is_signed {
-                            match (in_width, out_width) {
-                                // FIXME(antoyo): the function _mm_cvtepi8_epi16 should directly
-                                // call an intrinsic equivalent to __builtin_ia32_pmovsxbw128 so that
-                                // we can generate a call to it.
-                                (8, 16) => extend(bx.i8_type, bx.i16_type),
-                                (8, 32) => extend(bx.i8_type, bx.i32_type),
-                                (8, 64) => extend(bx.i8_type, bx.i64_type),
-                                (16, 32) => extend(bx.i16_type, bx.i32_type),
-                                (32, 64) => extend(bx.i32_type, bx.i64_type),
-                                (16, 64) => extend(bx.i16_type, bx.i64_type),
-                                _ => unimplemented!("in: {}, out: {}", in_width, out_width),
-                            }
-                        } else {
-                            match (in_width, out_width) {
-                                (8, 16) => extend(bx.u8_type, bx.u16_type),
-                                (8, 32) => extend(bx.u8_type, bx.u32_type),
-                                (8, 64) => extend(bx.u8_type, bx.u64_type),
-                                (16, 32) => extend(bx.u16_type, bx.u32_type),
-                                (16, 64) => extend(bx.u16_type, bx.u64_type),
-                                (32, 64) => extend(bx.u32_type, bx.u64_type),
-                                _ => unimplemented!("in: {}, out: {}", in_width, out_width),
-                            }
-                        }
-                    }
-                });
-            }
-            (Style::Int(_), Style::Float) => {
-                unimplemented!();
-            }
-            (Style::Float, Style::Int(_)) => {
-                unimplemented!();
-            }
-            (Style::Float, Style::Float) => {
-                unimplemented!();
+            (Style::Int(_), Style::Int(_)) if in_width == out_width => return Ok(args[0].immediate()),
+            (Style::Int(_) | Style::Float, Style::Int(_) | Style::Float) => {
+                let in_type = element_gcc_type(bx, in_elem);
+                let out_type = element_gcc_type(bx, out_elem);
+                // NOTE: `as` casts from float to int saturate, while the result of simd_cast is
+                // undefined for out-of-range values.
+                let saturating = name == sym::simd_as && matches!(in_style, Style::Float) && matches!(out_style, Style::Int(_));
+                return Ok(convert_elements(bx, args[0].immediate(), in_type, out_type, in_len, saturating));
             }
             _ => { /* Unsupported. Fallthrough. */ }
         }
@@ -540,5 +524,383 @@ pub fn generic_simd_intrinsic<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>,
         return Ok(bx.context.new_bitcast(None, result, vec_ty));
     }
 
+    if name == sym::simd_select {
+        require_simd!(arg_tys[1], "argument");
+        let (vector_len, _) = arg_tys[1].simd_size_and_type(bx.tcx());
+        require!(
+            in_len == vector_len,
+            "mismatched lengths: mask length `{}` != other vector length `{}`",
+            in_len,
+            vector_len
+        );
+        match in_elem.kind() {
+            ty::Int(_) | ty::Uint(_) => (),
+            _ => return_error!("mask element type is `{}`, expected `i_`", in_elem),
+        }
+        let mask = args[0].immediate();
+        let lane_masks = (0..in_len)
+            .map(|index| {
+                let lane = vector_lane(bx, mask, index);
+                let zero = bx.context.new_rvalue_zero(lane.get_type());
+                bx.context.new_comparison(None, ComparisonOp::NotEquals, lane, zero)
+            })
+            .collect::<Vec<_>>();
+        return Ok(select_lanes(bx, &lane_masks, args[1].immediate(), args[2].immediate()));
+    }
+
+    if name == sym::simd_bitmask {
+        // The `fn simd_bitmask(vector) -> unsigned integer` intrinsic takes a vector of integers
+        // and returns an integer whose bit `i` is the most significant bit of the element `i`.
+        match in_elem.kind() {
+            ty::Int(_) | ty::Uint(_) | ty::RawPtr(_) => (),
+            _ => return_error!(
+                "vector argument `{}`'s element type `{}`, expected integer element type",
+                in_ty,
+                in_elem
+            ),
+        }
+        let expected_int_bits = in_len.max(8);
+        match ret_ty.kind() {
+            ty::Uint(i) if i.bit_width() == Some(expected_int_bits) => (),
+            _ => return_error!("bitmask `{}`, expected `u{}`", ret_ty, expected_int_bits),
+        }
+
+        let vector = args[0].immediate();
+        let mut result = bx.context.new_rvalue_zero(llret_ty);
+        for index in 0..in_len {
+            let lane = vector_lane(bx, vector, index);
+            let lane_type = lane.get_type();
+            let most_significant_bit = bx.context.new_rvalue_from_long(lane_type, lane_type.get_size() as i64 * 8 - 1);
+            let bit = bx.context.new_binary_op(None, BinaryOp::RShift, lane_type, lane, most_significant_bit);
+            let bit = bx.context.new_binary_op(None, BinaryOp::BitwiseAnd, lane_type, bit, bx.context.new_rvalue_one(lane_type));
+            let bit = bx.context.new_cast(None, bit, llret_ty);
+            let shift = bx.context.new_rvalue_from_long(llret_ty, index as i64);
+            let bit = bx.context.new_binary_op(None, BinaryOp::LShift, llret_ty, bit, shift);
+            result = bx.context.new_binary_op(None, BinaryOp::BitwiseOr, llret_ty, result, bit);
+        }
+        return Ok(result);
+    }
+
+    if name == sym::simd_gather || name == sym::simd_scatter {
+        // simd_gather(values: <N x T>, pointers: <N x *const T>, mask: <N x i{M}>) -> <N x T>
+        // simd_scatter(values: <N x T>, pointers: <N x *mut T>, mask: <N x i{M}>) -> ()
+        // * N: number of elements in the input vectors
+        // * T: type of the element to load or store
+        // * M: any integer width is supported
+        require_simd!(arg_tys[1], "second");
+        require_simd!(arg_tys[2], "third");
+        let (pointers_len, pointer_elem) = arg_tys[1].simd_size_and_type(bx.tcx());
+        let (mask_len, mask_elem) = arg_tys[2].simd_size_and_type(bx.tcx());
+        require!(
+            in_len == pointers_len && in_len == mask_len,
+            "expected the second and third arguments to have length {} (same as first argument `{}`), \
+             found `{}` with length {} and `{}` with length {}",
+            in_len,
+            in_ty,
+            arg_tys[1],
+            pointers_len,
+            arg_tys[2],
+            mask_len
+        );
+        match pointer_elem.kind() {
+            ty::RawPtr(pointer) if pointer.ty == in_elem => (),
+            _ => return_error!(
+                "expected element type `{}` of second argument `{}` to be a pointer to the element type `{}` of the first argument `{}`",
+                pointer_elem,
+                arg_tys[1],
+                in_elem,
+                in_ty
+            ),
+        }
+        match mask_elem.kind() {
+            ty::Int(_) | ty::Uint(_) => (),
+            _ => return_error!(
+                "expected element type `{}` of third argument `{}` to be a signed integer type",
+                mask_elem,
+                arg_tys[2]
+            ),
+        }
+
+        let values = args[0].immediate();
+        let pointers = args[1].immediate();
+        let mask = args[2].immediate();
+        let element_type = values.get_type().unqualified().dyncast_vector().expect("vector type").get_element_type();
+        let pointer_type = element_type.make_pointer();
+        let func = bx.current_func();
+        // NOTE: the masked-out elements are loaded from or stored to this local, so that no branch
+        // is needed.
+        let array_type = bx.context.new_array_type(None, element_type, in_len as i32);
+        let fallback = func.new_local(None, array_type, "gather_scatter_fallback");
+        if name == sym::simd_gather {
+            bx.llbb().add_assignment(None, fallback, bx.context.new_bitcast(None, values, array_type));
+        }
+
+        let mut elements = vec![];
+        for index in 0..in_len {
+            let mask_lane = vector_lane(bx, mask, index);
+            let zero = bx.context.new_rvalue_zero(mask_lane.get_type());
+            let enabled = bx.context.new_comparison(None, ComparisonOp::NotEquals, mask_lane, zero);
+            let gcc_index = bx.context.new_rvalue_from_long(bx.int_type, index as i64);
+            let fallback_pointer = bx.context.new_array_access(None, fallback.to_rvalue(), gcc_index).get_address(None);
+            let pointer = select_pointer(bx, enabled, vector_lane(bx, pointers, index), fallback_pointer, pointer_type);
+            if name == sym::simd_gather {
+                elements.push(pointer.dereference(None).to_rvalue());
+            }
+            else {
+                bx.llbb().add_assignment(None, pointer.dereference(None), vector_lane(bx, values, index));
+            }
+        }
+
+        if name == sym::simd_scatter {
+            // NOTE: return the same dummy value as the calls to a function without return value.
+            return Ok(bx.context.new_rvalue_from_long(bx.isize_type, 0));
+        }
+        // NOTE: assign to a local so that the loads are done here.
+        let result = func.new_local(None, values.get_type(), "gather_result");
+        bx.llbb().add_assignment(None, result, bx.context.new_rvalue_from_vector(None, values.get_type(), &elements));
+        return Ok(result.to_rvalue());
+    }
+
+    macro_rules! require_reduction_element {
+        ($($kind: ident),*) => {
+            match in_elem.kind() {
+                $(ty::$kind(_))|* => (),
+                _ => return_error!(
+                    "unsupported {} from `{}` with element `{}` to `{}`",
+                    name,
+                    in_ty,
+                    in_elem,
+                    ret_ty
+                ),
+            }
+        };
+    }
+
+    macro_rules! require_reduction_return {
+        () => {
+            require!(
+                ret_ty == in_elem,
+                "expected return type `{}` (element of input `{}`), found `{}`",
+                in_elem,
+                in_ty,
+                ret_ty
+            )
+        };
+    }
+
+    let arithmetic_reduction =
+        match name {
+            sym::simd_reduce_add_ordered => Some((true, true)),
+            sym::simd_reduce_add_unordered => Some((true, false)),
+            sym::simd_reduce_mul_ordered => Some((false, true)),
+            sym::simd_reduce_mul_unordered => Some((false, false)),
+            _ => None,
+        };
+    if let Some((is_add, ordered)) = arithmetic_reduction {
+        require_reduction_return!();
+        require_reduction_element!(Int, Uint, Float);
+        let is_float = matches!(in_elem.kind(), ty::Float(_));
+        // TODO(antoyo): use a tree reduction for the unordered float reductions, like LLVM: they are
+        // done in order for now.
+        let accumulator = if ordered { Some(args[1].immediate()) } else { None };
+        return Ok(reduce_lanes(bx, args[0].immediate(), in_len, accumulator, |bx, a, b| {
+            match (is_add, is_float) {
+                (true, false) => bx.add(a, b),
+                (true, true) => bx.fadd(a, b),
+                (false, false) => bx.mul(a, b),
+                (false, true) => bx.fmul(a, b),
+            }
+        }));
+    }
+
+    let minmax_reduction =
+        match name {
+            sym::simd_reduce_min | sym::simd_reduce_min_nanless => Some(true),
+            sym::simd_reduce_max | sym::simd_reduce_max_nanless => Some(false),
+            _ => None,
+        };
+    if let Some(is_min) = minmax_reduction {
+        require_reduction_return!();
+        require_reduction_element!(Int, Uint, Float);
+        let vector = args[0].immediate();
+        let result =
+            match *in_elem.kind() {
+                ty::Float(f) => {
+                    let builtin_name =
+                        match (is_min, f.bit_width()) {
+                            (true, 32) => "fminf",
+                            (true, _) => "fmin",
+                            (false, 32) => "fmaxf",
+                            (false, _) => "fmax",
+                        };
+                    let builtin = bx.context.get_builtin_function(builtin_name);
+                    reduce_lanes(bx, vector, in_len, None, |bx, a, b| bx.context.new_call(None, builtin, &[a, b]))
+                },
+                _ => {
+                    let signed = matches!(in_elem.kind(), ty::Int(_));
+                    let predicate =
+                        match (is_min, signed) {
+                            (true, true) => IntPredicate::IntSLT,
+                            (true, false) => IntPredicate::IntULT,
+                            (false, true) => IntPredicate::IntSGT,
+                            (false, false) => IntPredicate::IntUGT,
+                        };
+                    reduce_lanes(bx, vector, in_len, None, |bx, a, b| {
+                        let condition = bx.icmp(predicate, a, b);
+                        bx.select(condition, a, b)
+                    })
+                },
+            };
+        return Ok(result);
+    }
+
+    let bitwise_reduction =
+        match name {
+            sym::simd_reduce_and => Some((BinaryOp::BitwiseAnd, false)),
+            sym::simd_reduce_or => Some((BinaryOp::BitwiseOr, false)),
+            sym::simd_reduce_xor => Some((BinaryOp::BitwiseXor, false)),
+            sym::simd_reduce_all => Some((BinaryOp::BitwiseAnd, true)),
+            sym::simd_reduce_any => Some((BinaryOp::BitwiseOr, true)),
+            _ => None,
+        };
+    if let Some((operator, boolean)) = bitwise_reduction {
+        if !boolean {
+            require_reduction_return!();
+        }
+        require_reduction_element!(Int, Uint);
+        let result = reduce_lanes(bx, args[0].immediate(), in_len, None, |bx, a, b| {
+            bx.context.new_binary_op(None, operator, a.get_type(), a, b)
+        });
+        if !boolean {
+            return Ok(result);
+        }
+        // NOTE: the elements of the masks are either all ones or zero, so the result of the
+        // reduction is non-zero when all (or any) elements are set.
+        let zero = bx.context.new_rvalue_zero(result.get_type());
+        let result = bx.context.new_comparison(None, ComparisonOp::NotEquals, result, zero);
+        return Ok(bx.context.new_cast(None, result, llret_ty));
+    }
+
     unimplemented!("simd {}", name);
 }
+
+/// Returns the GCC type of the elements of a vector whose Rust element type is `elem`.
+fn element_gcc_type<'gcc, 'tcx>(bx: &Builder<'_, 'gcc, 'tcx>, elem: Ty<'tcx>) -> Type<'gcc> {
+    match *elem.kind() {
+        ty::Int(i) => bx.cx.type_int_from_ty(i),
+        ty::Uint(u) => bx.cx.type_uint_from_ty(u),
+        ty::Float(f) => bx.cx.type_float_from_ty(f),
+        // NOTE: vectors of pointers are vectors of pointer-sized integers.
+        ty::RawPtr(_) => bx.cx.usize_type,
+        _ => unreachable!("unexpected vector element type {:?}", elem),
+    }
+}
+
+fn vector_lane<'gcc>(bx: &Builder<'_, 'gcc, '_>, vector: RValue<'gcc>, index: u64) -> RValue<'gcc> {
+    let index = bx.context.new_rvalue_from_long(bx.int_type, index as i64);
+    bx.context.new_vector_access(None, vector, index).to_rvalue()
+}
+
+/// Converts each of the `len` elements of `vector` from `in_type` to `out_type`. The conversions
+/// from float to int saturate like `as` if `saturating` is true.
+fn convert_elements<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>, vector: RValue<'gcc>, in_type: Type<'gcc>, out_type: Type<'gcc>, len: u64, saturating: bool) -> RValue<'gcc> {
+    let vector_type = bx.context.new_vector_type(out_type, len);
+    let array_type = bx.context.new_array_type(None, in_type, len as i32);
+    // TODO(antoyo): switch to using new_vector_access or __builtin_convertvector for vector casting.
+    let array = bx.context.new_bitcast(None, vector, array_type);
+
+    let mut elements = vec![];
+    for index in 0..len {
+        let index = bx.context.new_rvalue_from_long(bx.int_type, index as i64);
+        let element = bx.context.new_array_access(None, array, index).to_rvalue();
+        let element =
+            if saturating {
+                saturating_float_to_int(bx, element, out_type)
+            }
+            else {
+                bx.context.new_cast(None, element, out_type)
+            };
+        elements.push(element);
+    }
+
+    bx.context.new_rvalue_from_vector(None, vector_type, &elements)
+}
+
+/// Converts the float `value` to `int_type` like `as` does: NaN becomes 0 and the out-of-range
+/// values become the minimum or the maximum of `int_type`.
+fn saturating_float_to_int<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>, value: RValue<'gcc>, int_type: Type<'gcc>) -> RValue<'gcc> {
+    let float_type = value.get_type();
+    let width = int_type.get_size() as i32 * 8;
+    let signed = int_type.is_signed(bx.cx);
+    let (min, max, upper_bound) =
+        if signed {
+            (i64::MIN >> (64 - width), i64::MAX >> (64 - width), 2_f64.powi(width - 1))
+        }
+        else {
+            (0, (u64::MAX >> (64 - width)) as i64, 2_f64.powi(width))
+        };
+    let lower_bound = bx.context.new_rvalue_from_double(float_type, min as f64);
+    let upper_bound = bx.context.new_rvalue_from_double(float_type, upper_bound);
+
+    // NOTE: all the comparisons are false for NaN.
+    let not_too_small = bx.context.new_comparison(None, ComparisonOp::GreaterThanEquals, value, lower_bound);
+    let not_too_big = bx.context.new_comparison(None, ComparisonOp::LessThan, value, upper_bound);
+    let in_range = bx.context.new_binary_op(None, BinaryOp::LogicalAnd, bx.bool_type, not_too_small, not_too_big);
+    let too_small = bx.context.new_comparison(None, ComparisonOp::LessThan, value, lower_bound);
+    let too_big = bx.context.new_comparison(None, ComparisonOp::GreaterThanEquals, value, upper_bound);
+
+    let value = bx.select(in_range, value, bx.context.new_rvalue_zero(float_type));
+    let result = bx.context.new_cast(None, value, int_type);
+    let result = bx.select(too_small, bx.context.new_rvalue_from_long(int_type, min), result);
+    bx.select(too_big, bx.context.new_rvalue_from_long(int_type, max), result)
+}
+
+/// Returns the vector whose elements are the ones of `then_vector` where `lane_masks` is true and
+/// the ones of `else_vector` elsewhere.
+fn select_lanes<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>, lane_masks: &[RValue<'gcc>], then_vector: RValue<'gcc>, else_vector: RValue<'gcc>) -> RValue<'gcc> {
+    let vector_type = then_vector.get_type();
+    let element_type = vector_type.unqualified().dyncast_vector().expect("vector type").get_element_type();
+    let int_type = bx.type_ix(element_type.get_size() as u64 * 8);
+    let int_vector_type = bx.context.new_vector_type(int_type, lane_masks.len() as u64);
+    // NOTE: the elements of the mask are all ones where the condition is true, so that the
+    // selection can be done with bitwise operations.
+    let elements = lane_masks.iter()
+        .map(|&lane_mask| {
+            let lane_mask = bx.context.new_cast(None, lane_mask, int_type);
+            bx.context.new_unary_op(None, UnaryOp::Minus, int_type, lane_mask)
+        })
+        .collect::<Vec<_>>();
+    let mask = bx.context.new_rvalue_from_vector(None, int_vector_type, &elements);
+    let inverted_mask = bx.context.new_unary_op(None, UnaryOp::BitwiseNegate, int_vector_type, mask);
+    let then_vector = bx.context.new_bitcast(None, then_vector, int_vector_type);
+    let else_vector = bx.context.new_bitcast(None, else_vector, int_vector_type);
+    let then_vector = bx.context.new_binary_op(None, BinaryOp::BitwiseAnd, int_vector_type, then_vector, mask);
+    let else_vector = bx.context.new_binary_op(None, BinaryOp::BitwiseAnd, int_vector_type, else_vector, inverted_mask);
+    let result = bx.context.new_binary_op(None, BinaryOp::BitwiseOr, int_vector_type, then_vector, else_vector);
+    bx.context.new_bitcast(None, result, vector_type)
+}
+
+/// Returns `pointer` (a pointer-sized integer) as a pointer of type `pointer_type` if `condition`
+/// is true, `fallback` otherwise.
+fn select_pointer<'gcc>(bx: &Builder<'_, 'gcc, '_>, condition: RValue<'gcc>, pointer: RValue<'gcc>, fallback: RValue<'gcc>, pointer_type: Type<'gcc>) -> RValue<'gcc> {
+    let usize_type = bx.usize_type;
+    let mask = bx.context.new_cast(None, condition, usize_type);
+    let mask = bx.context.new_unary_op(None, UnaryOp::Minus, usize_type, mask);
+    let inverted_mask = bx.context.new_unary_op(None, UnaryOp::BitwiseNegate, usize_type, mask);
+    let pointer = bx.context.new_cast(None, pointer, usize_type);
+    let fallback = bx.context.new_cast(None, fallback, usize_type);
+    let pointer = bx.context.new_binary_op(None, BinaryOp::BitwiseAnd, usize_type, pointer, mask);
+    let fallback = bx.context.new_binary_op(None, BinaryOp::BitwiseAnd, usize_type, fallback, inverted_mask);
+    let pointer = bx.context.new_binary_op(None, BinaryOp::BitwiseOr, usize_type, pointer, fallback);
+    bx.context.new_cast(None, pointer, pointer_type)
+}
+
+/// Folds the `len` elements of `vector` with `operation`, starting with `accumulator` if any.
+fn reduce_lanes<'a, 'gcc, 'tcx>(bx: &mut Builder<'a, 'gcc, 'tcx>, vector: RValue<'gcc>, len: u64, accumulator: Option<RValue<'gcc>>, mut operation: impl FnMut(&mut Builder<'a, 'gcc, 'tcx>, RValue<'gcc>, RValue<'gcc>) -> RValue<'gcc>) -> RValue<'gcc> {
+    let mut lanes = (0..len).map(|index| vector_lane(bx, vector, index)).collect::<Vec<_>>().into_iter();
+    let mut result = accumulator.unwrap_or_else(|| lanes.next().expect("lane"));
+    for lane in lanes {
+        result = operation(bx, result, lane);
+    }
+    result
+}
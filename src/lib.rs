@@ -49,6 +49,7 @@ mod type_;
 mod type_of;
 
 use std::any::Any;
+use std::env;
 use std::sync::{Arc, Mutex};
 
 use gccjit::{Context, OptimizationLevel, CType};
@@ -92,14 +93,10 @@ impl CodegenBackend for GccCodegenBackend {
             sess.warn("LTO is not supported. You may get a linker error.");
         }
 
-        let temp_dir = TempDir::new().expect("cannot create temporary directory");
-        let temp_file = temp_dir.into_path().join("result.asm");
-        let check_context = Context::default();
-        check_context.set_print_errors_to_stderr(false);
-        let _int128_ty = check_context.new_c_type(CType::UInt128t);
-        // NOTE: we cannot just call compile() as this would require other files than libgccjit.so.
-        check_context.compile_to_file(gccjit::OutputKind::Assembler, temp_file.to_str().expect("path to str"));
-        *self.supports_128bit_integers.lock().expect("lock") = check_context.get_last_error() == Ok(None);
+        // NOTE: the env var allows testing the emulation of 128-bit integers with a libgccjit that
+        // supports them.
+        let emulate_128bit_integers = env::var("CG_GCCJIT_EMULATE_128BIT_INTEGERS").as_deref() == Ok("1");
+        *self.supports_128bit_integers.lock().expect("lock") = supports_type(sess, CType::UInt128t) && !emulate_128bit_integers;
     }
 
     fn provide(&self, providers: &mut Providers) {
@@ -263,6 +260,20 @@ pub fn __rustc_codegen_backend() -> Box<dyn CodegenBackend> {
     })
 }
 
+/// Check whether the libgccjit in use can compile code using the type `typ` for the target of the
+/// session.
+fn supports_type(sess: &Session, typ: CType) -> bool {
+    let temp_dir = TempDir::new().expect("cannot create temporary directory");
+    let temp_file = temp_dir.path().join("result.asm");
+    let check_context = Context::default();
+    base::add_target_options(&check_context, sess);
+    check_context.set_print_errors_to_stderr(false);
+    let _typ = check_context.new_c_type(typ);
+    // NOTE: we cannot just call compile() as this would require other files than libgccjit.so.
+    check_context.compile_to_file(gccjit::OutputKind::Assembler, temp_file.to_str().expect("path to str"));
+    check_context.get_last_error() == Ok(None)
+}
+
 fn to_gcc_opt_level(optlevel: Option<OptLevel>) -> OptimizationLevel {
     match optlevel {
         None => OptimizationLevel::None,
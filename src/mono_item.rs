@@ -1,3 +1,4 @@
+use gccjit::FunctionType;
 use rustc_codegen_ssa::traits::PreDefineMethods;
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use rustc_middle::mir::mono::{Linkage, Visibility};
@@ -27,10 +28,19 @@ impl<'gcc, 'tcx> PreDefineMethods<'tcx> for CodegenCx<'gcc, 'tcx> {
         assert!(!instance.substs.needs_infer());
 
         let fn_abi = self.fn_abi_of_instance(instance, ty::List::empty());
-        self.linkage.set(base::linkage_to_gcc(linkage));
+        let attrs = self.tcx.codegen_fn_attrs(instance.def_id());
+        if attrs.flags.contains(CodegenFnAttrFlags::NAKED) {
+            // NOTE: the body of naked functions is emitted as global asm by codegen_naked_fn(), so
+            // only declare the function here.
+            self.linkage.set(FunctionType::Extern);
+        }
+        else {
+            self.linkage.set(base::linkage_to_gcc(linkage));
+        }
         let _decl = self.declare_fn(symbol_name, &fn_abi);
-        //let attrs = self.tcx.codegen_fn_attrs(instance.def_id());
 
+        // TODO(antoyo): set the cold attribute on the #[cold] functions when gccjit supports
+        // function attributes.
         // TODO(antoyo): call set_link_section() to allow initializing argc/argv.
         // TODO(antoyo): set unique comdat.
         // TODO(antoyo): use inline attribute from there in linkage.set() above.
@@ -33,7 +33,7 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
     }
 
     pub fn type_size_t(&self) -> Type<'gcc> {
-        self.context.new_type::<usize>()
+        self.sizet_type
     }
 
     pub fn type_u8(&self) -> Type<'gcc> {
@@ -66,6 +66,18 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
         self.context.new_vector_type(ty, len)
     }
 
+    /// A pointer to `ty` with the alignment of the data layout, like the isize and usize types.
+    pub fn type_aligned_pointer(&self, ty: Type<'gcc>) -> Type<'gcc> {
+        let pointer = ty.make_pointer();
+        let data_layout = &self.tcx.data_layout;
+        if data_layout.pointer_align.abi.bytes() < data_layout.pointer_size.bytes() {
+            pointer.get_aligned(data_layout.pointer_align.abi.bytes())
+        }
+        else {
+            pointer
+        }
+    }
+
     pub fn type_float_from_ty(&self, t: ty::FloatTy) -> Type<'gcc> {
         match t {
             ty::FloatTy::F32 => self.type_f32(),
@@ -104,28 +116,32 @@ impl<'gcc, 'tcx> BaseTypeMethods<'tcx> for CodegenCx<'gcc, 'tcx> {
     }
 
     fn type_f32(&self) -> Type<'gcc> {
-        self.context.new_type::<f32>()
+        self.float_type
     }
 
     fn type_f64(&self) -> Type<'gcc> {
-        self.context.new_type::<f64>()
+        self.double_type
     }
 
     fn type_func(&self, params: &[Type<'gcc>], return_type: Type<'gcc>) -> Type<'gcc> {
         self.context.new_function_pointer_type(None, return_type, params, false)
     }
 
-    fn type_struct(&self, fields: &[Type<'gcc>], _packed: bool) -> Type<'gcc> {
-        let types = fields.to_vec();
-        if let Some(typ) = self.struct_types.borrow().get(fields) {
-            return typ.clone();
+    fn type_struct(&self, fields: &[Type<'gcc>], packed: bool) -> Type<'gcc> {
+        // NOTE: the misaligned field types of a packed struct are new types every time, so packed
+        // structs are not cached.
+        if !packed {
+            if let Some(typ) = self.struct_types.borrow().get(fields) {
+                return typ.clone();
+            }
         }
-        let fields: Vec<_> = fields.iter().enumerate()
-            .map(|(index, field)| self.context.new_field(None, *field, &format!("field{}_TODO", index)))
+        let gcc_fields: Vec<_> = self.packed_struct_fields(fields, packed).into_iter().enumerate()
+            .map(|(index, field)| self.context.new_field(None, field, &format!("field{}_TODO", index)))
             .collect();
-        // TODO(antoyo): use packed.
-        let typ = self.context.new_struct_type(None, "struct", &fields).as_type();
-        self.struct_types.borrow_mut().insert(types, typ);
+        let typ = self.context.new_struct_type(None, "struct", &gcc_fields).as_type();
+        if !packed {
+            self.struct_types.borrow_mut().insert(fields.to_vec(), typ);
+        }
         typ
     }
 
@@ -133,10 +149,10 @@ impl<'gcc, 'tcx> BaseTypeMethods<'tcx> for CodegenCx<'gcc, 'tcx> {
         if self.is_int_type_or_bool(typ) {
             TypeKind::Integer
         }
-        else if typ.is_compatible_with(self.float_type) {
+        else if typ.is_f32(self) {
             TypeKind::Float
         }
-        else if typ.is_compatible_with(self.double_type) {
+        else if typ.is_f64(self) {
             TypeKind::Double
         }
         else if typ.is_vector() {
@@ -149,12 +165,12 @@ impl<'gcc, 'tcx> BaseTypeMethods<'tcx> for CodegenCx<'gcc, 'tcx> {
     }
 
     fn type_ptr_to(&self, ty: Type<'gcc>) -> Type<'gcc> {
-        ty.make_pointer()
+        self.type_aligned_pointer(ty)
     }
 
     fn type_ptr_to_ext(&self, ty: Type<'gcc>, _address_space: AddressSpace) -> Type<'gcc> {
         // TODO(antoyo): use address_space, perhaps with TYPE_ADDR_SPACE?
-        ty.make_pointer()
+        self.type_aligned_pointer(ty)
     }
 
     fn element_type(&self, ty: Type<'gcc>) -> Type<'gcc> {
@@ -177,12 +193,10 @@ impl<'gcc, 'tcx> BaseTypeMethods<'tcx> for CodegenCx<'gcc, 'tcx> {
     }
 
     fn float_width(&self, typ: Type<'gcc>) -> usize {
-        let f32 = self.context.new_type::<f32>();
-        let f64 = self.context.new_type::<f64>();
-        if typ.is_compatible_with(f32) {
+        if typ.is_f32(self) {
             32
         }
-        else if typ.is_compatible_with(f64) {
+        else if typ.is_f64(self) {
             64
         }
         else {
@@ -209,14 +223,29 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
         self.type_array(self.type_from_integer(unit), size / unit_size)
     }
 
-    pub fn set_struct_body(&self, typ: Struct<'gcc>, fields: &[Type<'gcc>], _packed: bool) {
-        // TODO(antoyo): use packed.
-        let fields: Vec<_> = fields.iter().enumerate()
-            .map(|(index, field)| self.context.new_field(None, *field, &format!("field_{}", index)))
+    pub fn set_struct_body(&self, typ: Struct<'gcc>, fields: &[Type<'gcc>], packed: bool) {
+        let fields: Vec<_> = self.packed_struct_fields(fields, packed).into_iter().enumerate()
+            .map(|(index, field)| self.context.new_field(None, field, &format!("field_{}", index)))
             .collect();
         typ.set_fields(None, &fields);
     }
 
+    /// The types of the fields of a struct, which are placed right after each other when it is
+    /// packed.
+    ///
+    /// NOTE: libgccjit does not support packed structs, but GCC lays out a field whose type has an
+    /// alignment of 1 right after the previous one, which gives the same layout.
+    fn packed_struct_fields(&self, fields: &[Type<'gcc>], packed: bool) -> Vec<Type<'gcc>> {
+        if packed {
+            fields.iter()
+                .map(|field| field.unqualified().get_aligned(1))
+                .collect()
+        }
+        else {
+            fields.to_vec()
+        }
+    }
+
     pub fn type_named_struct(&self, name: &str) -> Struct<'gcc> {
         self.context.new_opaque_struct_type(None, name)
     }
@@ -243,27 +272,41 @@ impl<'gcc, 'tcx> CodegenCx<'gcc, 'tcx> {
     }
 }
 
-pub fn struct_fields<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, layout: TyAndLayout<'tcx>) -> (Vec<Type<'gcc>>, bool) {
+/// The types of the fields of a struct and the padding between them, at the offsets of the rustc
+/// layout.
+///
+/// NOTE: the fields of packed structs are misaligned here by giving them the alignment they have in
+/// the rustc layout, so that the struct keeps the alignment of the rustc layout. Thus, the struct
+/// type must not be packed afterwards.
+pub fn struct_fields<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, layout: TyAndLayout<'tcx>) -> Vec<Type<'gcc>> {
     let field_count = layout.fields.count();
 
-    let mut packed = false;
     let mut offset = Size::ZERO;
     let mut prev_effective_align = layout.align.abi;
     let mut result: Vec<_> = Vec::with_capacity(1 + field_count * 2);
+    let mut gcc_layout = GccStructLayout::new();
     for i in layout.fields.index_by_increasing_offset() {
         let target_offset = layout.fields.offset(i as usize);
         let field = layout.field(cx, i);
         let effective_field_align =
             layout.align.abi.min(field.align.abi).restrict_for_offset(target_offset);
-        packed |= effective_field_align < field.align.abi;
 
         assert!(target_offset >= offset);
         let padding = target_offset - offset;
         let padding_align = prev_effective_align.min(effective_field_align);
         assert_eq!(offset.align_to(padding_align) + padding, target_offset);
         result.push(cx.type_padding_filler(padding, padding_align));
+        gcc_layout.add_field(padding, Integer::approximate_align(cx, padding_align).align(cx).abi);
 
-        result.push(field.gcc_type(cx, !field.ty.is_any_ptr())); // FIXME(antoyo): might need to check if the type is inside another, like Box<Type>.
+        let mut field_type = field.gcc_type(cx, !field.ty.is_any_ptr()); // FIXME(antoyo): might need to check if the type is inside another, like Box<Type>.
+        if effective_field_align < field.align.abi {
+            // NOTE: GCC lays out a field whose type has a reduced alignment at a misaligned offset.
+            field_type = field_type.unqualified().get_aligned(effective_field_align.bytes());
+        }
+        result.push(field_type);
+        if gcc_layout.add_field(field.size, effective_field_align) != target_offset {
+            bug!("field {} of {} is at a different offset in GCC: {:#?}", i, layout.ty, layout);
+        }
         offset = target_offset + field.size;
         prev_effective_align = effective_field_align;
     }
@@ -275,8 +318,45 @@ pub fn struct_fields<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, layout: TyAndLayout
         let padding_align = prev_effective_align;
         assert_eq!(offset.align_to(padding_align) + padding, layout.size);
         result.push(cx.type_padding_filler(padding, padding_align));
+        gcc_layout.add_field(padding, Integer::approximate_align(cx, padding_align).align(cx).abi);
         assert_eq!(result.len(), 1 + field_count * 2);
+
+        // NOTE: a field type whose alignment in GCC differs from the rustc layout would silently
+        // change the size of the struct and thus the ABI.
+        if gcc_layout.size() != layout.size {
+            bug!("{} has size {:?} in GCC instead of {:?}: {:#?}", layout.ty, gcc_layout.size(), layout.size, layout);
+        }
     }
 
-    (result, packed)
+    result
+}
+
+/// Self-check of the struct types given to GCC.
+///
+/// NOTE: libgccjit cannot give the size of a struct type, so this lays out the fields following
+/// the C rules that GCC uses, with the alignment given to each field type.
+struct GccStructLayout {
+    offset: Size,
+    align: Align,
+}
+
+impl GccStructLayout {
+    fn new() -> Self {
+        Self {
+            offset: Size::ZERO,
+            align: Align::from_bytes(1).expect("align"),
+        }
+    }
+
+    /// Adds a field and returns its offset.
+    fn add_field(&mut self, size: Size, align: Align) -> Size {
+        let field_offset = self.offset.align_to(align);
+        self.offset = field_offset + size;
+        self.align = self.align.max(align);
+        field_offset
+    }
+
+    fn size(&self) -> Size {
+        self.offset.align_to(self.align)
+    }
 }
@@ -53,6 +53,14 @@ pub fn uncached_gcc_type<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, layout: TyAndLa
         Abi::Scalar(_) => bug!("handled elsewhere"),
         Abi::Vector { ref element, count } => {
             let element = layout.scalar_gcc_type_at(cx, element, Size::ZERO);
+            // NOTE: GCC does not support vectors of pointers, so use pointer-sized integers instead.
+            let element =
+                if element.get_pointee().is_some() {
+                    cx.usize_type
+                }
+                else {
+                    element
+                };
             return cx.context.new_vector_type(element, count);
         },
         Abi::ScalarPair(..) => {
@@ -116,8 +124,8 @@ pub fn uncached_gcc_type<'gcc, 'tcx>(cx: &CodegenCx<'gcc, 'tcx>, layout: TyAndLa
         FieldsShape::Arbitrary { .. } =>
             match name {
                 None => {
-                    let (gcc_fields, packed) = struct_fields(cx, layout);
-                    cx.type_struct(&gcc_fields, packed)
+                    let gcc_fields = struct_fields(cx, layout);
+                    cx.type_struct(&gcc_fields, false)
                 },
                 Some(ref name) => {
                     let gcc_type = cx.type_named_struct(name);
@@ -201,8 +209,8 @@ impl<'tcx> LayoutGccExt<'tcx> for TyAndLayout<'tcx> {
             if let Some((struct_type, layout)) = type_to_set_fields {
                 // Since we might be trying to generate a type containing another type which is not
                 // completely generated yet, we deferred setting the fields until now.
-                let (fields, packed) = struct_fields(cx, layout);
-                cx.set_struct_body(struct_type, &fields, packed);
+                let fields = struct_fields(cx, layout);
+                cx.set_struct_body(struct_type, &fields, false);
             }
             return ty;
         }
@@ -229,8 +237,8 @@ impl<'tcx> LayoutGccExt<'tcx> for TyAndLayout<'tcx> {
         cx.types.borrow_mut().insert((self.ty, variant_index), ty);
 
         if let Some((ty, layout)) = defer {
-            let (fields, packed) = struct_fields(cx, layout);
-            cx.set_struct_body(ty, &fields, packed);
+            let fields = struct_fields(cx, layout);
+            cx.set_struct_body(ty, &fields, false);
         }
 
         ty
@@ -0,0 +1,17 @@
+// Compiler:
+//   stderr:
+//     ...
+//     ... : "cc");
+//     ...
+
+// compile-env: CG_GCCJIT_DUMP_GIMPLE=1
+
+use std::arch::asm;
+
+// NOTE: the asm does not access memory, so it only clobbers the flags.
+#[no_mangle]
+pub fn nomem() {
+    unsafe {
+        asm!("nop", options(nomem, nostack));
+    }
+}
@@ -0,0 +1,17 @@
+// Compiler:
+//   stderr:
+//     ...
+//     ... : "memory");
+//     ...
+
+// compile-env: CG_GCCJIT_DUMP_GIMPLE=1
+
+use std::arch::asm;
+
+// NOTE: the asm preserves the flags, so it only clobbers the memory.
+#[no_mangle]
+pub fn preserves_flags() {
+    unsafe {
+        asm!("nop", options(nostack, preserves_flags));
+    }
+}
@@ -0,0 +1,33 @@
+// Compiler:
+//   stderr:
+//     ...
+//     ...__asm__("lea...
+//     ...
+//
+// Asm:
+//   stdout:
+//     ...
+//     pure_nomem_twice:
+//     ...
+//     ...xor	eax, eax
+//     ...ret
+//     ...
+
+// compile-env: CG_GCCJIT_DUMP_GIMPLE=1
+
+use std::arch::asm;
+
+fn add(a: u64, b: u64) -> u64 {
+    let x: u64;
+    unsafe {
+        asm!("lea {}, [{} + {}]", out(reg) x, in(reg) a, in(reg) b, options(pure, nomem, nostack, preserves_flags));
+    }
+    x
+}
+
+// NOTE: a pure asm that does not read memory is not volatile, so GCC deduplicates the two asm,
+// folds the xor to 0 and removes the asm.
+#[no_mangle]
+pub fn pure_nomem_twice(a: u64, b: u64) -> u64 {
+    add(a, b) ^ add(a, b)
+}
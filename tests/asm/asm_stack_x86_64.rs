@@ -0,0 +1,24 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...sub rsp, ...
+//     ...
+//     ...push rax
+//     ...pop rax
+//     ...
+
+use std::arch::asm;
+
+// NOTE: without nostack, the asm may push to the stack, so the red zone is not used and even a
+// leaf function must move the stack pointer to make room for its buffer. The template is left
+// unchanged.
+#[no_mangle]
+pub fn uses_stack(index: usize) -> u8 {
+    let buffer = [index as u8; 64];
+    unsafe {
+        asm!("push rax", "pop rax");
+        std::ptr::read_volatile(&buffer[index % 64])
+    }
+}
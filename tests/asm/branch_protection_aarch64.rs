@@ -0,0 +1,20 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...paciasp
+//     ...
+//     ...note.gnu.property...
+//     ...
+
+// compile-flags: -Zbranch-protection=bti,pac-ret
+
+extern "C" {
+    fn callee(value: u64) -> u64;
+}
+
+#[no_mangle]
+pub fn with_branch_protection(value: u64) -> u64 {
+    unsafe { callee(value) + 1 }
+}
@@ -0,0 +1,20 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...endbr64
+//     ...
+//     ...note.gnu.property...
+//     ...
+
+// compile-flags: -Zcf-protection=full
+
+extern "C" {
+    fn callee(value: u64) -> u64;
+}
+
+#[no_mangle]
+pub fn with_cf_protection(value: u64) -> u64 {
+    unsafe { callee(value) + 1 }
+}
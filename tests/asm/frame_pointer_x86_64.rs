@@ -0,0 +1,18 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...rbp, rsp
+//     ...
+
+// compile-flags: -Cforce-frame-pointers=yes
+
+extern "C" {
+    fn callee(value: u64) -> u64;
+}
+
+#[no_mangle]
+pub fn with_frame_pointer(value: u64) -> u64 {
+    unsafe { callee(value) + 1 }
+}
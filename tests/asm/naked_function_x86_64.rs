@@ -0,0 +1,30 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...pushsection .text.naked_section,"ax",%progbits
+//     ...
+//     ...type naked_sub, %function
+//     ...naked_sub:
+//     ...
+//     ...popsection
+//     ...
+
+#![feature(naked_functions)]
+
+use std::arch::asm;
+
+#[naked]
+#[no_mangle]
+#[link_section = ".text.naked_section"]
+pub extern "C" fn naked_sub(a: i64, b: i64) -> i64 {
+    unsafe {
+        asm!(
+            "mov rax, rdi",
+            "sub rax, rsi",
+            "ret",
+            options(noreturn)
+        );
+    }
+}
@@ -0,0 +1,18 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...callee@GOTPCREL[rip]...
+//     ...
+
+// compile-flags: -Zplt=no
+
+extern "C" {
+    fn callee(value: u64) -> u64;
+}
+
+#[no_mangle]
+pub fn without_plt(value: u64) -> u64 {
+    unsafe { callee(value) + 1 }
+}
@@ -0,0 +1,17 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...rsp, ...
+//     ...
+
+// compile-flags: -Zno-redzone=yes
+
+// NOTE: without a red zone, even a leaf function must move the stack pointer to make room for its
+// buffer.
+#[no_mangle]
+pub fn leaf_buffer(index: usize) -> u8 {
+    let buffer = [index as u8; 64];
+    unsafe { std::ptr::read_volatile(&buffer[index % 64]) }
+}
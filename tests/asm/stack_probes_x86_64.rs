@@ -0,0 +1,21 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...QWORD PTR [rsp], 0
+//     ...
+
+extern "C" {
+    fn fill(buffer: *mut u8);
+}
+
+// NOTE: the frame is larger than a page, so every page must be probed on the way down.
+#[no_mangle]
+pub fn large_frame() -> u8 {
+    let mut buffer = [0_u8; 65536];
+    unsafe {
+        fill(buffer.as_mut_ptr());
+    }
+    buffer[0]
+}
@@ -0,0 +1,22 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...__stack_chk_fail...
+//     ...
+
+// compile-flags: -Zstack-protector=all
+
+extern "C" {
+    fn fill(buffer: *mut u8);
+}
+
+#[no_mangle]
+pub fn protected_buffer() -> u8 {
+    let mut buffer = [0_u8; 64];
+    unsafe {
+        fill(buffer.as_mut_ptr());
+    }
+    buffer[0]
+}
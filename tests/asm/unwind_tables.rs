@@ -0,0 +1,14 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...cfi_startproc
+//     ...
+
+// compile-flags: -Cforce-unwind-tables=yes
+
+#[no_mangle]
+pub fn with_unwind_tables(value: u64) -> u64 {
+    value.wrapping_mul(3)
+}
@@ -0,0 +1,20 @@
+// Compiler:
+//
+// Asm:
+//   stdout:
+//     ...
+//     ...1234
+//     ...
+
+use std::arch::asm;
+
+// NOTE: GCC does not know the vector registers, so their clobbers must be dropped instead of
+// making GCC fail on an unknown register name.
+#[no_mangle]
+pub fn clobber_vector_registers(value: u64) -> u64 {
+    let result;
+    unsafe {
+        asm!("addi {}, {}, 1234", out(reg) result, in(reg) value, out("v0") _, clobber_abi("C"));
+    }
+    result
+}
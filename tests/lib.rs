@@ -1,6 +1,7 @@
 use std::{
     env::{self, current_dir},
-    path::PathBuf,
+    fs,
+    path::{Path, PathBuf},
     process::Command,
 };
 
@@ -14,37 +15,179 @@ fn main() {
     let gcc_path = include_str!("../gcc_path");
     let gcc_path = gcc_path.trim();
     env::set_var("LD_LIBRARY_PATH", gcc_path);
+    run_tests(tempdir.path().to_path_buf(), current_dir.clone(), |_| true, false);
+    // NOTE: run the integer tests again with the emulation of 128-bit integers used on targets
+    // where GCC does not support them.
+    let tempdir = TempDir::new().expect("temp dir");
+    run_tests(tempdir.path().to_path_buf(), current_dir.clone(), |path| {
+        path.file_stem().expect("file_stem").to_str().expect("to_str").starts_with("int")
+    }, true);
+    let tempdir = TempDir::new().expect("temp dir");
+    run_asm_output_tests(tempdir.path().to_path_buf(), current_dir);
+}
+
+/// The architectures that a test can be written for.
+///
+/// NOTE: x86_64 comes before x86 so that a file named `asm_x86_64.rs` is not seen as a x86 test.
+const ARCHS: &[&str] = &["aarch64", "arm", "powerpc", "riscv64", "x86_64", "x86"];
+
+/// The architecture that the tests are compiled for, using the names of `target_arch`.
+fn target_arch(target_triple: Option<&str>) -> &str {
+    let arch = target_triple.map_or(env::consts::ARCH, |triple| triple.split('-').next().expect("arch"));
+    match arch {
+        "i386" | "i586" | "i686" => "x86",
+        _ if arch.starts_with("arm") || arch.starts_with("thumb") => "arm",
+        _ if arch.starts_with("riscv64") => "riscv64",
+        _ => arch,
+    }
+}
+
+/// The architecture a test is written for, if its file name contains one, e.g. `asm_aarch64.rs`.
+/// Such a test is only run when targeting this architecture.
+fn test_arch(path: &Path) -> Option<&'static str> {
+    let stem = path.file_stem().expect("file_stem").to_str().expect("to_str");
+    // NOTE: the inline assembly test is written for x86-64.
+    if stem == "asm" {
+        return Some("x86_64");
+    }
+    ARCHS.iter().copied()
+        .find(|arch| {
+            stem == *arch || stem.starts_with(&format!("{}_", arch)) || stem.ends_with(&format!("_{}", arch))
+                || stem.contains(&format!("_{}_", arch))
+        })
+}
+
+fn is_test_for_target(path: &Path, target_arch: &str) -> bool {
+    path.extension().expect("extension").to_str().expect("to_str") == "rs"
+        && test_arch(path).map_or(true, |arch| arch == target_arch)
+}
+
+/// The header of a test is its first comment block.
+fn extract_header(source: &str) -> Option<String> {
+    let lines =
+        source.lines()
+            .skip_while(|l| !l.starts_with("//"))
+            .take_while(|l| l.starts_with("//"))
+            .map(|l| &l[2..])
+            .collect::<Vec<_>>()
+            .join("\n");
+    Some(lines)
+}
+
+/// The flags from the `// compile-flags:` lines of a test, which must come after its header.
+fn compile_flags(path: &Path) -> Vec<String> {
+    let source = fs::read_to_string(path).expect("read test");
+    source.lines()
+        .filter_map(|line| line.strip_prefix("// compile-flags:"))
+        .flat_map(|flags| flags.split_whitespace().map(|flag| flag.to_string()))
+        .collect()
+}
+
+/// The environment variables from the `// compile-env:` lines of a test, e.g.
+/// `// compile-env: CG_GCCJIT_DUMP_GIMPLE=1` to check the GIMPLE given to GCC in the `stderr` of
+/// the compiler.
+fn compile_env(path: &Path) -> Vec<(String, String)> {
+    let source = fs::read_to_string(path).expect("read test");
+    source.lines()
+        .filter_map(|line| line.strip_prefix("// compile-env:"))
+        .map(|var| {
+            let (name, value) = var.trim().split_once('=').expect("compile-env NAME=value");
+            (name.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+fn compiler_command(current_dir: &str, target_triple: Option<&str>, linker: Option<&str>) -> Command {
+    let mut compiler = Command::new("rustc");
+    compiler.args(&[
+        &format!("-Zcodegen-backend={}/target/debug/librustc_codegen_gcc.so", current_dir),
+        "--sysroot", &format!("{}/build_sysroot/sysroot/", current_dir),
+        "-Zno-parallel-llvm",
+        "-C", "panic=abort",
+    ]);
+    if let Some(target_triple) = target_triple {
+        compiler.args(&["--target", target_triple]);
+    }
+    if let Some(linker) = linker {
+        compiler.args(&["-C", &format!("linker={}", linker)]);
+    }
+    compiler
+}
+
+fn run_tests(tempdir: PathBuf, current_dir: String, filter: fn(&Path) -> bool, emulate_128bit_integers: bool) {
+    // NOTE: set TARGET_TRIPLE to run the tests for another target, e.g. i686-unknown-linux-gnu.
+    let target_triple = env::var("TARGET_TRIPLE").ok();
+    let arch = target_arch(target_triple.as_deref()).to_string();
+    // NOTE: set LINKER and RUN_WRAPPER to run the tests of a foreign target, e.g. in qemu-user.
+    let linker = env::var("LINKER").ok();
+    let run_wrapper = env::var("RUN_WRAPPER").ok().filter(|wrapper| !wrapper.trim().is_empty());
     LangTester::new()
         .test_dir("tests/run")
-        .test_file_filter(|path| path.extension().expect("extension").to_str().expect("to_str") == "rs")
-        .test_extract(|source| {
-            let lines =
-                source.lines()
-                    .skip_while(|l| !l.starts_with("//"))
-                    .take_while(|l| l.starts_with("//"))
-                    .map(|l| &l[2..])
-                    .collect::<Vec<_>>()
-                    .join("\n");
-            Some(lines)
-        })
+        .test_file_filter(move |path| is_test_for_target(path, &arch) && filter(path))
+        .test_extract(extract_header)
         .test_cmds(move |path| {
             // Test command 1: Compile `x.rs` into `tempdir/x`.
             let mut exe = PathBuf::new();
             exe.push(&tempdir);
             exe.push(path.file_stem().expect("file_stem"));
-            let mut compiler = Command::new("rustc");
+            let mut compiler = compiler_command(&current_dir, target_triple.as_deref(), linker.as_deref());
             compiler.args(&[
-                &format!("-Zcodegen-backend={}/target/debug/librustc_codegen_gcc.so", current_dir),
-                "--sysroot", &format!("{}/build_sysroot/sysroot/", current_dir),
-                "-Zno-parallel-llvm",
-                "-C", "panic=abort",
                 "-C", "link-arg=-lc",
                 "-o", exe.to_str().expect("to_str"),
                 path.to_str().expect("to_str"),
             ]);
+            if emulate_128bit_integers {
+                compiler.env("CG_GCCJIT_EMULATE_128BIT_INTEGERS", "1");
+            }
             // Test command 2: run `tempdir/x`.
-            let runtime = Command::new(exe);
+            let runtime =
+                match run_wrapper {
+                    Some(ref run_wrapper) => {
+                        let mut words = run_wrapper.split_whitespace();
+                        let mut runtime = Command::new(words.next().expect("run wrapper"));
+                        runtime.args(words).arg(exe);
+                        runtime
+                    },
+                    None => Command::new(exe),
+                };
             vec![("Compiler", compiler), ("Run-time", runtime)]
         })
         .run();
 }
+
+/// Run the tests that check the assembly generated for a library crate.
+///
+/// The expected assembly goes in the `stdout` of the `Asm` command of the header, and the flags
+/// and environment variables needed by a test go in its `// compile-flags:` and `// compile-env:`
+/// lines.
+fn run_asm_output_tests(tempdir: PathBuf, current_dir: String) {
+    let target_triple = env::var("TARGET_TRIPLE").ok();
+    let arch = target_arch(target_triple.as_deref()).to_string();
+    LangTester::new()
+        .test_dir("tests/asm")
+        .test_file_filter(move |path| is_test_for_target(path, &arch))
+        .test_extract(extract_header)
+        .test_cmds(move |path| {
+            // Test command 1: Compile `x.rs` into `tempdir/x.s`.
+            let mut asm = PathBuf::new();
+            asm.push(&tempdir);
+            asm.push(path.file_stem().expect("file_stem"));
+            asm.set_extension("s");
+            let mut compiler = compiler_command(&current_dir, target_triple.as_deref(), None);
+            compiler.args(&[
+                "--crate-type", "lib",
+                "--emit", "asm",
+                "-C", "codegen-units=1",
+                "-C", "opt-level=2",
+                "-o", asm.to_str().expect("to_str"),
+            ]);
+            compiler.args(compile_flags(path));
+            compiler.envs(compile_env(path));
+            compiler.arg(path.to_str().expect("to_str"));
+            // Test command 2: print `tempdir/x.s`.
+            let mut output = Command::new("cat");
+            output.arg(asm);
+            vec![("Compiler", compiler), ("Asm", output)]
+        })
+        .run();
+}
@@ -0,0 +1,37 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+fn main() {
+    use std::arch::aarch64::*;
+
+    // NOTE: the number of arguments is 1, it is used so that the operations are not constant-folded.
+    let one = std::env::args().count() as u8;
+
+    unsafe {
+        let bytes = [3_u8, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3];
+        let a = vld1q_u8(bytes.as_ptr());
+        assert_eq!(vmaxvq_u8(a), 9);
+        assert_eq!(vminvq_u8(a), 1);
+        assert_eq!(vaddlvq_u8(a), 80);
+        assert_eq!(vaddlvq_s8(vdupq_n_s8(-2 * one as i8)), -32);
+
+        let b = vdupq_n_u8(4 * one);
+        let mut result = [0_u8; 16];
+        vst1q_u8(result.as_mut_ptr(), vabdq_u8(a, b));
+        assert_eq!(result, [1, 3, 0, 3, 1, 5, 2, 2, 1, 1, 1, 4, 5, 3, 5, 1]);
+
+        vst1q_u8(result.as_mut_ptr(), vpmaxq_u8(a, b));
+        assert_eq!(result, [3, 4, 9, 6, 5, 8, 9, 9, 4, 4, 4, 4, 4, 4, 4, 4]);
+
+        let indices = [15_u8, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 16 * one];
+        vst1q_u8(result.as_mut_ptr(), vqtbl1q_u8(a, vld1q_u8(indices.as_ptr())));
+        assert_eq!(result, [3, 9, 7, 9, 8, 5, 3, 5, 6, 2, 9, 5, 1, 4, 1, 0]);
+
+        let wide = vdupq_n_u16(300 * one as u16);
+        let mut narrow = [0_u8; 8];
+        vst1_u8(narrow.as_mut_ptr(), vqmovn_u16(wide));
+        assert_eq!(narrow, [255; 8]);
+    }
+}
@@ -3,7 +3,7 @@
 // Run-time:
 //   status: 0
 
-#![feature(asm_const, asm_sym)]
+#![feature(asm_const, asm_sym, naked_functions)]
 
 use std::arch::{asm, global_asm};
 
@@ -19,6 +19,30 @@ extern "C" {
     fn add_asm(a: i64, b: i64) -> i64;
 }
 
+#[naked]
+extern "C" fn add_naked(a: i64, b: i64) -> i64 {
+    unsafe {
+        asm!(
+            "lea rax, [rdi + rsi]",
+            "ret",
+            options(noreturn)
+        );
+    }
+}
+
+#[naked]
+#[link_section = ".text.naked_section"]
+extern "C" fn sub_naked(a: i64, b: i64) -> i64 {
+    unsafe {
+        asm!(
+            "mov rax, rdi",
+            "sub rax, rsi",
+            "ret",
+            options(noreturn)
+        );
+    }
+}
+
 pub unsafe fn mem_cpy(dst: *mut u8, src: *const u8, len: usize) {
     asm!(
         "rep movsb",
@@ -163,6 +187,9 @@ fn main() {
 
     assert_eq!(unsafe { add_asm(40, 2) }, 42);
 
+    assert_eq!(add_naked(40, 2), 42);
+    assert_eq!(sub_naked(44, 2), 42);
+
     let array1 = [1u8, 2, 3];
     let mut array2 = [0u8, 0, 0];
     unsafe {
@@ -0,0 +1,71 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+fn main() {
+    use std::arch::asm;
+
+    let x: u64;
+    unsafe {
+        asm!("mov {}, 42", out(reg) x);
+    }
+    assert_eq!(x, 42);
+
+    let mut x: u64 = 40;
+    unsafe {
+        asm!("add {0}, {0}, {1}", inout(reg) x, in(reg) 2_u64);
+    }
+    assert_eq!(x, 42);
+
+    let x: u32;
+    unsafe {
+        asm!("mov {:w}, 7", out(reg) x);
+    }
+    assert_eq!(x, 7);
+
+    let x: u64;
+    unsafe {
+        asm!("add {:x}, {:x}, {:x}", lateout(reg) x, in(reg) 40_u64, in(reg) 2_u64);
+    }
+    assert_eq!(x, 42);
+
+    // Explicit registers.
+    let x: u64;
+    unsafe {
+        asm!("add x0, x1, x2", out("x0") x, in("x1") 40_u64, in("x2") 2_u64);
+    }
+    assert_eq!(x, 42);
+
+    let mut x: u64 = 5;
+    unsafe {
+        asm!("lsl x3, x3, #1", inout("x3") x);
+    }
+    assert_eq!(x, 10);
+
+    // Vector registers.
+    let x: f64;
+    unsafe {
+        asm!("fadd {:d}, {:d}, {:d}", out(vreg) x, in(vreg) 1.5_f64, in(vreg) 2.5_f64);
+    }
+    assert_eq!(x, 4.0);
+
+    let x: f32;
+    unsafe {
+        asm!("fmul {:s}, {:s}, {:s}", out(vreg_low16) x, in(vreg_low16) 1.5_f32, in(vreg_low16) 3.0_f32);
+    }
+    assert_eq!(x, 4.5);
+
+    let x: f64;
+    unsafe {
+        asm!("fmul d0, d1, d2", out("v0") x, in("v1") 3.0_f64, in("v2") 2.0_f64);
+    }
+    assert_eq!(x, 6.0);
+
+    // Clobbers.
+    let x: u64;
+    unsafe {
+        asm!("mov x9, #3", "add {}, x9, #4", out(reg) x, out("x9") _);
+    }
+    assert_eq!(x, 7);
+}
@@ -0,0 +1,53 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+fn main() {
+    use std::arch::asm;
+
+    let x: u32;
+    unsafe {
+        asm!("mov {}, #42", out(reg) x);
+    }
+    assert_eq!(x, 42);
+
+    let mut x: u32 = 40;
+    unsafe {
+        asm!("add {0}, {0}, {1}", inout(reg) x, in(reg) 2_u32);
+    }
+    assert_eq!(x, 42);
+
+    // Explicit registers.
+    let x: u32;
+    unsafe {
+        asm!("add r0, r1, r2", out("r0") x, in("r1") 40_u32, in("r2") 2_u32);
+    }
+    assert_eq!(x, 42);
+
+    // Floating-point registers.
+    let x: f32;
+    unsafe {
+        asm!("vadd.f32 {}, {}, {}", out(sreg) x, in(sreg) 1.5_f32, in(sreg_low16) 2.5_f32);
+    }
+    assert_eq!(x, 4.0);
+
+    let x: f64;
+    unsafe {
+        asm!("vmul.f64 {}, {}, {}", out(dreg) x, in(dreg_low16) 1.5_f64, in(dreg_low8) 3.0_f64);
+    }
+    assert_eq!(x, 4.5);
+
+    let x: f64;
+    unsafe {
+        asm!("vsub.f64 d0, d1, d2", out("d0") x, in("d1") 3.0_f64, in("d2") 1.0_f64);
+    }
+    assert_eq!(x, 2.0);
+
+    // Clobbers.
+    let x: u32;
+    unsafe {
+        asm!("mov r4, #3", "add {}, r4, #4", out(reg) x, out("r4") _);
+    }
+    assert_eq!(x, 7);
+}
@@ -0,0 +1,39 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+fn main() {
+    use std::arch::asm;
+
+    let x: u32;
+    unsafe {
+        asm!("li {}, 42", out(reg) x);
+    }
+    assert_eq!(x, 42);
+
+    let mut x: u32 = 40;
+    unsafe {
+        asm!("addi {0}, {0}, 2", inout(reg_nonzero) x);
+    }
+    assert_eq!(x, 42);
+
+    let x: f64;
+    unsafe {
+        asm!("fadd {}, {}, {}", out(freg) x, in(freg) 1.5_f64, in(freg) 2.5_f64);
+    }
+    assert_eq!(x, 4.0);
+
+    // Explicit registers.
+    let x: u32;
+    unsafe {
+        asm!("add 3, 4, 5", out("r3") x, in("r4") 40_u32, in("r5") 2_u32);
+    }
+    assert_eq!(x, 42);
+
+    let x: f64;
+    unsafe {
+        asm!("fmul 0, 1, 2", out("f0") x, in("f1") 3.0_f64, in("f2") 2.0_f64);
+    }
+    assert_eq!(x, 6.0);
+}
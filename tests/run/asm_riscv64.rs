@@ -0,0 +1,47 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+fn main() {
+    use std::arch::asm;
+
+    let x: u64;
+    unsafe {
+        asm!("li {}, 42", out(reg) x);
+    }
+    assert_eq!(x, 42);
+
+    let mut x: u64 = 40;
+    unsafe {
+        asm!("add {0}, {0}, {1}", inout(reg) x, in(reg) 2_u64);
+    }
+    assert_eq!(x, 42);
+
+    // Explicit registers.
+    let x: u64;
+    unsafe {
+        asm!("add a0, a1, a2", out("x10") x, in("x11") 40_u64, in("x12") 2_u64);
+    }
+    assert_eq!(x, 42);
+
+    // Floating-point registers.
+    let x: f64;
+    unsafe {
+        asm!("fadd.d {}, {}, {}", out(freg) x, in(freg) 1.5_f64, in(freg) 2.5_f64);
+    }
+    assert_eq!(x, 4.0);
+
+    let x: f32;
+    unsafe {
+        asm!("fmul.s f0, f1, f2", out("f0") x, in("f1") 1.5_f32, in("f2") 3.0_f32);
+    }
+    assert_eq!(x, 4.5);
+
+    // Clobbers.
+    let x: u64;
+    unsafe {
+        asm!("li t0, 3", "addi {}, t0, 4", out(reg) x, out("x5") _);
+    }
+    assert_eq!(x, 7);
+}
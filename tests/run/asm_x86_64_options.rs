@@ -0,0 +1,84 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(bench_black_box)]
+
+use std::arch::asm;
+
+// NOTE: this is a leaf function, so GCC would use the red zone if the asm did not push to the
+// stack.
+#[inline(never)]
+fn stack() -> u64 {
+    let mut local = [1u64, 2, 3, 4];
+    let local_ptr = std::hint::black_box(&mut local).as_mut_ptr();
+    unsafe {
+        asm!(
+            "push 42",
+            "push 43",
+            "add rsp, 16",
+        );
+    }
+    unsafe { *local_ptr + *local_ptr.add(3) }
+}
+
+extern "C" fn forty_two() -> u64 {
+    42
+}
+
+fn main() {
+    assert_eq!(stack(), 5);
+
+    // Without nostack, the asm can call a function.
+    let x: u64;
+    unsafe {
+        asm!("call {}", in(reg) forty_two as usize, out("rax") x, clobber_abi("C"));
+    }
+    assert_eq!(x, 42);
+
+    // nostack: the stack is left untouched.
+    let x: u64;
+    unsafe {
+        asm!("lea {}, [{} + 2]", out(reg) x, in(reg) 40_u64, options(nostack));
+    }
+    assert_eq!(x, 42);
+
+    // pure and nomem: the asm can be deduplicated and removed.
+    let add = |a: u64, b: u64| -> u64 {
+        let x: u64;
+        unsafe {
+            asm!("lea {}, [{} + {}]", out(reg) x, in(reg) a, in(reg) b, options(pure, nomem, nostack, preserves_flags));
+        }
+        x
+    };
+    assert_eq!(add(40, 2), 42);
+    assert_eq!(add(40, 2) + add(40, 2), 84);
+
+    // pure and readonly: the asm must see the store that precedes it.
+    let mut value = 1_u64;
+    let read = |ptr: *const u64| -> u64 {
+        let x: u64;
+        unsafe {
+            asm!("mov {}, [{}]", out(reg) x, in(reg) ptr, options(pure, readonly, nostack, preserves_flags));
+        }
+        x
+    };
+    assert_eq!(read(&value), 1);
+    value = 42;
+    assert_eq!(read(&value), 42);
+
+    // Without nomem, the asm can write memory.
+    let mut value = 0_u64;
+    unsafe {
+        asm!("mov qword ptr [{}], 42", in(reg) &mut value, options(nostack, preserves_flags));
+    }
+    assert_eq!(value, 42);
+
+    // preserves_flags: the flags are not modified by the asm.
+    let mut x = 41_u64;
+    unsafe {
+        asm!("lea {0}, [{0} + 1]", inout(reg) x, options(nomem, nostack, preserves_flags));
+    }
+    assert_eq!(x, 42);
+}
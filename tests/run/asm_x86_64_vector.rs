@@ -0,0 +1,67 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(avx512_target_feature, stdsimd)]
+
+use std::arch::asm;
+use std::arch::x86_64::*;
+
+#[target_feature(enable = "avx2")]
+unsafe fn ymm() {
+    let a = _mm256_set1_epi32(40);
+    let b = _mm256_set1_epi32(2);
+    let x: __m256i;
+    asm!("vpaddd ymm0, ymm1, ymm2", out("ymm0") x, in("ymm1") a, in("ymm2") b);
+    let mut result = [0i32; 8];
+    _mm256_storeu_si256(result.as_mut_ptr() as *mut __m256i, x);
+    assert_eq!(result, [42; 8]);
+
+    let x: __m128i;
+    asm!("paddd {0}, xmm3", inout(xmm_reg) _mm_set1_epi32(40) => x, in("xmm3") _mm_set1_epi32(2));
+    let mut result = [0i32; 4];
+    _mm_storeu_si128(result.as_mut_ptr() as *mut __m128i, x);
+    assert_eq!(result, [42; 4]);
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn zmm_and_kreg() {
+    let a = _mm512_set1_epi32(40);
+    let b = _mm512_set1_epi32(2);
+    let x: __m512i;
+    asm!("vpaddd zmm16, zmm17, zmm18", out("zmm16") x, in("zmm17") a, in("zmm18") b);
+    assert_eq!(_mm512_reduce_add_epi32(x), 42 * 16);
+
+    // Only add the lanes selected by the mask.
+    let mask: u16 = 0b0000_0000_1111_1111;
+    let x: __m512i;
+    asm!("vpaddd {0} {{{1}}}, {2}, {3}", inout(zmm_reg) a => x, in(kreg) mask, in(zmm_reg) a, in(zmm_reg) b);
+    assert_eq!(_mm512_reduce_add_epi32(x), 42 * 8 + 40 * 8);
+
+    let mask: u16;
+    asm!("kxnorw k1, k1, k1", out("k1") mask);
+    assert_eq!(mask, 0xffff);
+
+    let mask: u16;
+    asm!("kmovw {0}, {1}", out(kreg) mask, in(reg) 0x1234_u32);
+    assert_eq!(mask, 0x1234);
+}
+
+fn clobbers() {
+    let x: u64;
+    unsafe {
+        asm!("fld1", "fstp st(0)", "mov {}, 42", out(reg) x, out("st(0)") _, out("mm0") _);
+    }
+    assert_eq!(x, 42);
+}
+
+fn main() {
+    if is_x86_feature_detected!("avx2") {
+        unsafe { ymm() };
+    }
+    if is_x86_feature_detected!("avx512f") {
+        unsafe { zmm_and_kreg() };
+    }
+    clobbers();
+}
@@ -0,0 +1,86 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(cmpxchg16b_target_feature, stdsimd)]
+
+use std::sync::atomic::{AtomicI8, AtomicI32, AtomicI64, AtomicPtr, AtomicU8, AtomicU32, AtomicU64, Ordering};
+
+fn signed_extremums() {
+    let value = AtomicI32::new(-5);
+    assert_eq!(value.fetch_max(-10, Ordering::SeqCst), -5);
+    assert_eq!(value.load(Ordering::SeqCst), -5);
+    assert_eq!(value.fetch_max(3, Ordering::AcqRel), -5);
+    assert_eq!(value.fetch_min(-7, Ordering::Release), 3);
+    assert_eq!(value.load(Ordering::Acquire), -7);
+
+    let value = AtomicI8::new(i8::MIN);
+    assert_eq!(value.fetch_max(1, Ordering::Relaxed), i8::MIN);
+    assert_eq!(value.fetch_min(-1, Ordering::Relaxed), 1);
+    assert_eq!(value.load(Ordering::Relaxed), -1);
+
+    let value = AtomicI64::new(i64::MAX);
+    assert_eq!(value.fetch_min(i64::MIN, Ordering::SeqCst), i64::MAX);
+    assert_eq!(value.load(Ordering::SeqCst), i64::MIN);
+}
+
+fn unsigned_extremums() {
+    let value = AtomicU32::new(5);
+    assert_eq!(value.fetch_max(u32::MAX, Ordering::SeqCst), 5);
+    assert_eq!(value.fetch_min(0x8000_0000, Ordering::SeqCst), u32::MAX);
+    assert_eq!(value.load(Ordering::SeqCst), 0x8000_0000);
+
+    let value = AtomicU8::new(200);
+    assert_eq!(value.fetch_max(100, Ordering::Relaxed), 200);
+    assert_eq!(value.fetch_min(100, Ordering::Relaxed), 200);
+    assert_eq!(value.load(Ordering::Relaxed), 100);
+}
+
+fn read_modify_write() {
+    let value = AtomicU64::new(0b1100);
+    assert_eq!(value.fetch_add(1, Ordering::SeqCst), 0b1100);
+    assert_eq!(value.fetch_sub(2, Ordering::SeqCst), 0b1101);
+    assert_eq!(value.fetch_and(0b0110, Ordering::SeqCst), 0b1011);
+    assert_eq!(value.fetch_nand(0b0011, Ordering::SeqCst), 0b0010);
+    assert_eq!(value.fetch_or(0b1, Ordering::SeqCst), !0b0010);
+    assert_eq!(value.fetch_xor(u64::MAX, Ordering::SeqCst), !0b0010);
+    assert_eq!(value.swap(42, Ordering::SeqCst), 0b0010);
+    assert_eq!(value.compare_exchange(41, 0, Ordering::SeqCst, Ordering::Relaxed), Err(42));
+    assert_eq!(value.compare_exchange(42, 0, Ordering::AcqRel, Ordering::Acquire), Ok(42));
+    assert_eq!(value.load(Ordering::SeqCst), 0);
+
+    let mut first = 1;
+    let mut second = 2;
+    let pointer = AtomicPtr::new(&mut first);
+    let previous = pointer.swap(&mut second, Ordering::SeqCst);
+    assert_eq!(unsafe { *previous }, 1);
+    assert_eq!(unsafe { *pointer.load(Ordering::SeqCst) }, 2);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "cmpxchg16b")]
+unsafe fn compare_exchange16() {
+    use std::arch::x86_64::cmpxchg16b;
+
+    let mut value: u128 = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321;
+    let previous = cmpxchg16b(&mut value, 0, 1, Ordering::SeqCst, Ordering::SeqCst);
+    assert_eq!(previous, 0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+    assert_eq!(value, 0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+    let previous = cmpxchg16b(&mut value, previous, u128::MAX, Ordering::AcqRel, Ordering::Acquire);
+    assert_eq!(previous, 0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+    assert_eq!(value, u128::MAX);
+}
+
+fn main() {
+    signed_extremums();
+    unsigned_extremums();
+    read_modify_write();
+
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("cmpxchg16b") {
+        unsafe {
+            compare_exchange16();
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(core_intrinsics)]
+
+use std::intrinsics::{assume, likely, unlikely};
+
+#[inline(never)]
+fn get(slice: &[u32], index: usize) -> u32 {
+    unsafe {
+        assume(index < slice.len());
+    }
+    slice[index]
+}
+
+#[inline(never)]
+fn classify(value: u32) -> u32 {
+    if likely(value < 100) {
+        1
+    }
+    else if unlikely(value == 1000) {
+        2
+    }
+    else {
+        3
+    }
+}
+
+fn main() {
+    let array = [1, 2, 3];
+    assert_eq!(get(&array, 2), 3);
+
+    assert_eq!(classify(42), 1);
+    assert_eq!(classify(1000), 2);
+    assert_eq!(classify(500), 3);
+
+    let argc = std::env::args().count();
+    assert!(likely(argc == 1));
+    assert!(unlikely(argc == 1));
+    assert!(!unlikely(argc == 2));
+}
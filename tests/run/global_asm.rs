@@ -0,0 +1,123 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(asm_const, asm_sym)]
+
+use std::arch::{asm, global_asm};
+
+#[cfg(target_arch = "x86_64")]
+global_asm!("
+    .global add_att
+add_att:
+    movq %rdi, %rax
+    addq %rsi, %rax
+    addq ${}, %rax
+    ret",
+    const 0,
+    options(att_syntax),
+);
+
+// Intel syntax must be restored after an AT&T global asm.
+#[cfg(target_arch = "x86_64")]
+global_asm!("
+    .global add_intel
+add_intel:
+    lea rax, [rdi + rsi + {}]
+    ret",
+    const 0,
+);
+
+#[cfg(target_arch = "aarch64")]
+global_asm!("
+    .global add_asm
+add_asm:
+    add x0, x0, x1
+    add x0, x0, #{}
+    ret",
+    const 0,
+);
+
+#[cfg(target_arch = "riscv64")]
+global_asm!("
+    .global add_asm
+add_asm:
+    add a0, a0, a1
+    addi a0, a0, {}
+    ret",
+    const 0,
+);
+
+extern "C" {
+    #[cfg(target_arch = "x86_64")]
+    fn add_att(a: u64, b: u64) -> u64;
+    #[cfg(target_arch = "x86_64")]
+    fn add_intel(a: u64, b: u64) -> u64;
+    #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
+    fn add_asm(a: u64, b: u64) -> u64;
+}
+
+// NOTE: only referenced by sym operands.
+static VALUE: u64 = 42;
+
+#[inline(never)]
+extern "C" fn generic<T>() -> u64 {
+    std::mem::size_of::<T>() as u64
+}
+
+#[cfg(target_arch = "x86_64")]
+fn main() {
+    assert_eq!(unsafe { add_att(40, 2) }, 42);
+    assert_eq!(unsafe { add_intel(40, 2) }, 42);
+
+    let x: u64;
+    unsafe {
+        asm!("mov {}, qword ptr [rip + {}]", out(reg) x, sym VALUE);
+    }
+    assert_eq!(x, 42);
+
+    let x: u64;
+    unsafe {
+        asm!("call {}", sym generic::<[u8; 42]>, out("rax") x, clobber_abi("C"));
+    }
+    assert_eq!(x, 42);
+}
+
+#[cfg(target_arch = "aarch64")]
+fn main() {
+    assert_eq!(unsafe { add_asm(40, 2) }, 42);
+
+    let x: u64;
+    unsafe {
+        asm!("adrp {0}, {1}", "ldr {0}, [{0}, :lo12:{1}]", out(reg) x, sym VALUE);
+    }
+    assert_eq!(x, 42);
+
+    let x: u64;
+    unsafe {
+        asm!("bl {}", sym generic::<[u8; 42]>, out("x0") x, clobber_abi("C"));
+    }
+    assert_eq!(x, 42);
+}
+
+#[cfg(target_arch = "riscv64")]
+fn main() {
+    assert_eq!(unsafe { add_asm(40, 2) }, 42);
+
+    let x: u64;
+    unsafe {
+        asm!("la {0}, {1}", "ld {0}, 0({0})", out(reg) x, sym VALUE);
+    }
+    assert_eq!(x, 42);
+
+    let x: u64;
+    unsafe {
+        asm!("call {}", sym generic::<[u8; 42]>, out("a0") x, clobber_abi("C"));
+    }
+    assert_eq!(x, 42);
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "riscv64")))]
+fn main() {
+}
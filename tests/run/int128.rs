@@ -0,0 +1,123 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(core_intrinsics, start)]
+
+#![no_std]
+
+use core::intrinsics::{bitreverse, bswap, ctlz, ctpop, cttz};
+
+#[panic_handler]
+fn panic_handler(_: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+/*
+ * Code
+ */
+
+#[start]
+fn main(argc: isize, _argv: *const *const u8) -> isize {
+    // NOTE: argc is 1, it is used so that the operations are not constant-folded.
+    let one = argc as u128;
+    let a = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210_u128 * one;
+    let b = 0xffff_0000_1111_2222_u128 * one;
+    let c = (i128::MIN + 12345) * argc as i128;
+    let d = -0x1234_5678_9abc_def0_1234_5678_i128 * argc as i128;
+
+    // Arithmetic.
+    assert_eq!(a + b, 0x0123_4567_89ab_cdf0_fedb_ba98_8765_5432);
+    assert_eq!(a - b, 0x0123_4567_89ab_cdee_fedd_ba98_6543_0fee);
+    assert_eq!(a.wrapping_mul(b), 0x82d8_40da_72c3_8a81_7c04_7ae1_48e4_c620);
+    assert_eq!(a / b, 0x0123_468a_d023_32e5);
+    assert_eq!(a % b, 0x74a9_c3cc_8eb0_05a6);
+    assert_eq!(c / 7, -24305883351495604533098186245126299054);
+    assert_eq!(c % 7, -5);
+    assert_eq!(d / -1000, 5634002667681019488906794);
+    assert_eq!(d % -1000, -616);
+    assert_eq!(d * 1000, -5634002667681019488906794616000);
+    assert_eq!(-d, 0x1234_5678_9abc_def0_1234_5678);
+    assert_eq!(-(one as i128), -1);
+    assert_eq!((0_i128 * argc as i128).wrapping_neg(), 0);
+    assert_eq!(i128::MIN.wrapping_neg() * argc as i128, i128::MIN);
+
+    // Bitwise operations.
+    assert_eq!(!a, 0xfedc_ba98_7654_3210_0123_4567_89ab_cdef);
+    assert_eq!(a & b, 0x0000_0000_0000_0000_fedc_0000_1010_2200);
+    assert_eq!(a | b, 0x0123_4567_89ab_cdef_ffff_ba98_7755_3232);
+    assert_eq!(a ^ b, 0x0123_4567_89ab_cdef_0123_ba98_6745_1032);
+    assert_eq!(d >> 3, -704250333460127436113349327);
+    assert_eq!((d as u128) >> 3, 42535295864413057599461698492857677105);
+
+    // Comparisons.
+    assert!(a > b);
+    assert!(b < a);
+    assert!(a >= a);
+    assert!(a != b);
+    assert!(c < d);
+    assert!(d > c);
+    assert!(c <= c);
+    assert!(d < 0);
+    assert!((d as u128) > a);
+    assert!((-(one as i128)) < one as i128);
+    assert!((one << 64) > u64::MAX as u128);
+    assert!(((one << 64) as i128) > -(1_i128 << 64));
+
+    // Casts.
+    assert_eq!(a as u64, 0xfedc_ba98_7654_3210);
+    assert_eq!(a as i8, 0x10);
+    assert_eq!(d as i64, 7296712173568108936);
+    assert_eq!(-(argc as i64) as i128, -1);
+    assert_eq!(-(argc as i64) as u128, u128::MAX);
+    assert_eq!(u64::MAX as u128 * one, 0xffff_ffff_ffff_ffff);
+    assert_eq!(-(argc as i32) as u32 as u128, 0xffff_ffff);
+    assert_eq!((one << 100) as f64, 1267650600228229401496703205376.0);
+    assert_eq!((one << 90) as f32, 1237940039285380274899124224.0);
+    assert_eq!(-((one << 70) as i128) as f64, -1180591620717411303424.0);
+    assert_eq!(1267650600228229401496703205376.0_f64 * argc as f64, (one << 100) as f64);
+    assert_eq!((1267650600228229401496703205376.0_f64 * argc as f64) as u128, one << 100);
+    assert_eq!((-1180591620717411303424.0_f64 * argc as f64) as i128, -(1_i128 << 70));
+    assert_eq!((1237940039285380274899124224.0_f32 * argc as f32) as u128, one << 90);
+
+    // Intrinsics.
+    unsafe {
+        assert_eq!(bswap(a), 0x1032_5476_98ba_dcfe_efcd_ab89_6745_2301);
+        assert_eq!(ctlz(a), 7);
+        assert_eq!(ctlz(b), 64);
+        assert_eq!(ctlz(0 * one), 128);
+        assert_eq!(cttz(a), 4);
+        assert_eq!(cttz(one << 100), 100);
+        assert_eq!(cttz(0 * one), 128);
+        assert_eq!(ctpop(a), 64);
+        assert_eq!(ctpop(d), 81);
+        assert_eq!(bitreverse(a), 0x084c_2a6e_195d_3b7f_f7b3_d591_e6a2_c480);
+    }
+    assert_eq!(a.rotate_left(12), 0x3456_789a_bcde_ffed_cba9_8765_4321_0012);
+    assert_eq!(a.rotate_right(12), 0x2100_1234_5678_9abc_deff_edcb_a987_6543);
+    assert_eq!(a.leading_zeros(), 7);
+    assert_eq!((d as u128).leading_ones(), 35);
+
+    // Saturating and overflowing operations.
+    assert_eq!(u128::MAX.saturating_add(one), u128::MAX);
+    assert_eq!(a.saturating_add(b), a + b);
+    assert_eq!(b.saturating_sub(a), 0);
+    assert_eq!(a.saturating_sub(b), a - b);
+    assert_eq!(i128::MAX.saturating_add(one as i128), i128::MAX);
+    assert_eq!(c.saturating_sub((one as i128) << 64), i128::MIN);
+    assert_eq!(d.saturating_add(one as i128), d + 1);
+    assert_eq!(d.saturating_sub(one as i128), d - 1);
+    assert_eq!(u128::MAX.checked_add(one), None);
+    assert_eq!(a.checked_add(b), Some(a + b));
+    assert_eq!(b.checked_sub(a), None);
+    assert_eq!(a.checked_mul(3), Some(4537098225612512841997066109049411120));
+    assert_eq!(a.checked_mul(b), None);
+    assert_eq!(c.checked_sub((one as i128) << 64), None);
+    assert_eq!(i128::MIN.checked_mul(-(argc as i128)), None);
+    assert_eq!(d.checked_mul(1000), Some(-5634002667681019488906794616000));
+    assert_eq!(a.overflowing_add(1 << 127), (171653549535673402679019659085567242768, false));
+    assert_eq!(i128::MAX.overflowing_add(one as i128), (i128::MIN, true));
+
+    0
+}
@@ -0,0 +1,38 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+static WORDS: [u32; 2] = [0x0102_0304, 0x0506_0708];
+static SECOND_WORD: &u32 = &WORDS[1];
+static BIG: u128 = 0x0011_2233_4455_6677_8899_aabb_ccdd_eeff;
+
+fn main() {
+    let bytes = unsafe { *(&WORDS as *const [u32; 2] as *const [u8; 8]) };
+    #[cfg(target_endian = "little")]
+    assert_eq!(bytes, [4, 3, 2, 1, 8, 7, 6, 5]);
+    #[cfg(target_endian = "big")]
+    assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(*SECOND_WORD, 0x0506_0708);
+
+    // NOTE: the number of arguments is 1, it is used so that the operations are not constant-folded.
+    let one = std::env::args().count() as u128;
+    let value = BIG * one;
+    assert_eq!(value.to_be_bytes(), [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+    ]);
+    assert_eq!(u128::from_ne_bytes(value.to_ne_bytes()), BIG);
+    assert_eq!(u128::from_le_bytes(value.to_le_bytes()), BIG);
+    assert_eq!(value >> 64, 0x0011_2233_4455_6677);
+    assert_eq!(value as u64, 0x8899_aabb_ccdd_eeff);
+    assert_eq!(value << 64, 0x8899_aabb_ccdd_eeff_0000_0000_0000_0000);
+    assert_eq!(value.swap_bytes(), 0xffee_ddcc_bbaa_9988_7766_5544_3322_1100);
+
+    let halves: [u64; 2] = unsafe { std::mem::transmute(value) };
+    #[cfg(target_endian = "little")]
+    assert_eq!(halves, [0x8899_aabb_ccdd_eeff, 0x0011_2233_4455_6677]);
+    #[cfg(target_endian = "big")]
+    assert_eq!(halves, [0x0011_2233_4455_6677, 0x8899_aabb_ccdd_eeff]);
+    let halves: [u64; 2] = unsafe { std::mem::transmute(BIG) };
+    assert_eq!(unsafe { std::mem::transmute::<_, u128>(halves) }, value);
+}
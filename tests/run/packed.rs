@@ -0,0 +1,94 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+use std::ptr;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+struct Packed {
+    a: u8,
+    b: u32,
+    c: u64,
+    d: u16,
+}
+
+#[repr(C, packed(2))]
+#[derive(Clone, Copy)]
+struct Packed2 {
+    a: u8,
+    b: u64,
+}
+
+#[repr(C)]
+struct Outer {
+    a: u8,
+    inner: Packed2,
+    b: u32,
+}
+
+// NOTE: the initializer of this static has a pointer at a misaligned offset.
+#[repr(C, packed)]
+struct PackedRef {
+    a: u8,
+    reference: &'static u32,
+}
+
+static VALUE: u32 = 42;
+static PACKED_REF: PackedRef = PackedRef { a: 1, reference: &VALUE };
+
+#[inline(never)]
+fn sum(packed: &Packed) -> u64 {
+    packed.a as u64 + packed.b as u64 + packed.c + packed.d as u64
+}
+
+#[inline(never)]
+fn read_u32_at(bytes: &[u8], offset: usize) -> u32 {
+    unsafe { ptr::read_unaligned(bytes.as_ptr().add(offset) as *const u32) }
+}
+
+#[inline(never)]
+fn write_u64_at(bytes: &mut [u8], offset: usize, value: u64) {
+    unsafe { ptr::write_unaligned(bytes.as_mut_ptr().add(offset) as *mut u64, value) }
+}
+
+fn main() {
+    assert_eq!(std::mem::size_of::<Packed>(), 15);
+    assert_eq!(std::mem::align_of::<Packed>(), 1);
+    assert_eq!(std::mem::size_of::<Packed2>(), 10);
+    assert_eq!(std::mem::align_of::<Packed2>(), 2);
+
+    let mut packed = Packed { a: 1, b: 2, c: 3, d: 4 };
+    assert_eq!(sum(&packed), 10);
+    packed.b = 20;
+    packed.c = 30;
+    assert_eq!({ packed.b }, 20);
+    assert_eq!({ packed.c }, 30);
+    assert_eq!(sum(&packed), 55);
+
+    let copy = packed;
+    assert_eq!(sum(&copy), 55);
+
+    let packed2 = [Packed2 { a: 1, b: u64::MAX }, Packed2 { a: 2, b: 42 }];
+    assert_eq!({ packed2[0].b }, u64::MAX);
+    assert_eq!({ packed2[1].b }, 42);
+    assert_eq!(packed2[1].a, 2);
+
+    assert_eq!(std::mem::size_of::<Outer>(), 16);
+    let outer = Outer { a: 1, inner: Packed2 { a: 2, b: 3 }, b: 4 };
+    assert_eq!(outer.a as u64 + outer.inner.a as u64 + { outer.inner.b } + outer.b as u64, 10);
+
+    let mut bytes = [0u8; 16];
+    write_u64_at(&mut bytes, 3, 0x0102030405060708);
+    assert_eq!(read_u32_at(&bytes, 3), u32::from_ne_bytes([bytes[3], bytes[4], bytes[5], bytes[6]]));
+    assert_eq!(unsafe { ptr::read_unaligned(bytes.as_ptr().add(3) as *const u64) }, 0x0102030405060708);
+
+    let value = unsafe { ptr::read_volatile(&packed.a) };
+    assert_eq!(value, 1);
+
+    assert_eq!(std::mem::size_of::<PackedRef>(), 1 + std::mem::size_of::<usize>());
+    assert_eq!(PACKED_REF.a, 1);
+    let reference = unsafe { ptr::read_unaligned(ptr::addr_of!(PACKED_REF.reference)) };
+    assert_eq!(*reference, 42);
+}
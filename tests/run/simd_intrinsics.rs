@@ -0,0 +1,125 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(platform_intrinsics, repr_simd)]
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct i32x4(i32, i32, i32, i32);
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct u32x4(u32, u32, u32, u32);
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct f32x4(f32, f32, f32, f32);
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct u8x4(u8, u8, u8, u8);
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ptrx4(*const i32, *const i32, *const i32, *const i32);
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct mutptrx4(*mut i32, *mut i32, *mut i32, *mut i32);
+
+extern "platform-intrinsic" {
+    fn simd_reduce_add_ordered<T, U>(x: T, accumulator: U) -> U;
+    fn simd_reduce_add_unordered<T, U>(x: T) -> U;
+    fn simd_reduce_mul_ordered<T, U>(x: T, accumulator: U) -> U;
+    fn simd_reduce_mul_unordered<T, U>(x: T) -> U;
+    fn simd_reduce_min<T, U>(x: T) -> U;
+    fn simd_reduce_max<T, U>(x: T) -> U;
+    fn simd_reduce_and<T, U>(x: T) -> U;
+    fn simd_reduce_or<T, U>(x: T) -> U;
+    fn simd_reduce_xor<T, U>(x: T) -> U;
+    fn simd_reduce_all<T>(x: T) -> bool;
+    fn simd_reduce_any<T>(x: T) -> bool;
+    fn simd_select<M, T>(mask: M, a: T, b: T) -> T;
+    fn simd_select_bitmask<M, T>(mask: M, a: T, b: T) -> T;
+    fn simd_bitmask<T, U>(x: T) -> U;
+    fn simd_gather<T, U, V>(values: T, pointers: U, mask: V) -> T;
+    fn simd_scatter<T, U, V>(values: T, pointers: U, mask: V);
+    fn simd_cast<T, U>(x: T) -> U;
+    fn simd_as<T, U>(x: T) -> U;
+}
+
+fn main() {
+    // NOTE: the number of arguments is 1, it is used so that the operations are not constant-folded.
+    let one = std::env::args().count() as i32;
+
+    let a = i32x4(one, -2, 3, 4);
+    let b = u32x4(5, one as u32, 7, 8);
+    let f = f32x4(1.5, -2.0, one as f32, 4.0);
+
+    unsafe {
+        assert_eq!(simd_reduce_add_ordered::<_, i32>(a, 10), 16);
+        assert_eq!(simd_reduce_add_unordered::<_, i32>(a), 6);
+        assert_eq!(simd_reduce_mul_ordered::<_, i32>(a, 2), -48);
+        assert_eq!(simd_reduce_mul_unordered::<_, u32>(b), 280);
+        assert_eq!(simd_reduce_add_ordered::<_, f32>(f, 0.5), 5.0);
+        assert_eq!(simd_reduce_mul_unordered::<_, f32>(f), -12.0);
+
+        assert_eq!(simd_reduce_min::<_, i32>(a), -2);
+        assert_eq!(simd_reduce_max::<_, i32>(a), 4);
+        assert_eq!(simd_reduce_min::<_, u32>(b), 1);
+        assert_eq!(simd_reduce_max::<_, u32>(b), 8);
+        assert_eq!(simd_reduce_min::<_, f32>(f), -2.0);
+        assert_eq!(simd_reduce_max::<_, f32>(f), 4.0);
+
+        assert_eq!(simd_reduce_and::<_, u32>(u32x4(0b1110, 0b0111, 0b1111, 0b0110)), 0b0110);
+        assert_eq!(simd_reduce_or::<_, u32>(u32x4(0b0001, 0b0100, one as u32 * 0b1000, 0)), 0b1101);
+        assert_eq!(simd_reduce_xor::<_, u32>(u32x4(0b0011, 0b0101, 0b1001, 0)), 0b1111);
+
+        let all_set = i32x4(-1, -1, -1, -one);
+        let some_set = i32x4(0, -1, 0, -one);
+        let none_set = i32x4(0, 0, 0, one - 1);
+        assert!(simd_reduce_all(all_set));
+        assert!(!simd_reduce_all(some_set));
+        assert!(simd_reduce_any(some_set));
+        assert!(!simd_reduce_any(none_set));
+
+        let selected: i32x4 = simd_select(some_set, a, i32x4(10, 20, 30, 40));
+        assert_eq!(selected, i32x4(10, -2, 30, 4));
+        let selected: f32x4 = simd_select(some_set, f, f32x4(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(selected, f32x4(0.0, -2.0, 0.0, 4.0));
+
+        let selected: i32x4 = simd_select_bitmask(0b0101_u8 * one as u8, a, i32x4(10, 20, 30, 40));
+        assert_eq!(selected, i32x4(1, 20, 3, 40));
+
+        let bitmask: u8 = simd_bitmask(some_set);
+        assert_eq!(bitmask, 0b1010);
+        let bitmask: u8 = simd_bitmask(a);
+        assert_eq!(bitmask, 0b0010);
+
+        let data = [100, 200, 300, 400];
+        let pointer = data.as_ptr();
+        let pointers = ptrx4(pointer.add(3), pointer.add(2), pointer.add(1), pointer);
+        let gathered = simd_gather(a, pointers, some_set);
+        assert_eq!(gathered, i32x4(one, 300, 3, 100));
+
+        let mut data = [0, 0, 0, 0];
+        let pointer = data.as_mut_ptr();
+        let pointers = mutptrx4(pointer.add(3), pointer.add(2), pointer.add(1), pointer);
+        simd_scatter(a, pointers, some_set);
+        assert_eq!(data, [4, 0, -2, 0]);
+
+        let converted: f32x4 = simd_cast(a);
+        assert_eq!(converted, f32x4(1.0, -2.0, 3.0, 4.0));
+        let converted: i32x4 = simd_cast(f);
+        assert_eq!(converted, i32x4(1, -2, 1, 4));
+        let converted: u8x4 = simd_cast(i32x4(255 * one, 256, 257, -1));
+        assert_eq!(converted, u8x4(255, 0, 1, 255));
+
+        let saturated: u8x4 = simd_as(f32x4(-1.5, 300.0 * one as f32, f32::NAN, 42.9));
+        assert_eq!(saturated, u8x4(0, 255, 0, 42));
+        let saturated: i32x4 = simd_as(f32x4(f32::INFINITY, f32::NEG_INFINITY, -7.9, 1e10 * one as f32));
+        assert_eq!(saturated, i32x4(i32::MAX, i32::MIN, -7, i32::MAX));
+    }
+}
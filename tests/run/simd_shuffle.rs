@@ -0,0 +1,63 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(platform_intrinsics, repr_simd)]
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct u16x2(u16, u16);
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct u16x4(u16, u16, u16, u16);
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct u16x8(u16, u16, u16, u16, u16, u16, u16, u16);
+
+#[repr(simd)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct i32x4(i32, i32, i32, i32);
+
+extern "platform-intrinsic" {
+    fn simd_shuffle2<T, U>(x: T, y: T, idx: [u32; 2]) -> U;
+    fn simd_shuffle4<T, U>(x: T, y: T, idx: [u32; 4]) -> U;
+    fn simd_shuffle8<T, U>(x: T, y: T, idx: [u32; 8]) -> U;
+    fn simd_insert<T, U>(x: T, idx: u32, value: U) -> T;
+    fn simd_extract<T, U>(x: T, idx: u32) -> U;
+}
+
+const REVERSE: [u32; 4] = [7, 6, 1, 0];
+const JOIN: [u32; 8] = [0, 4, 1, 5, 2, 6, 3, 7];
+const HIGH: [u32; 2] = [2, 3];
+
+// NOTE: the elements are smaller than a word so that a wrong element order on big-endian targets
+// shows up in the results.
+fn main() {
+    // NOTE: the number of arguments is 1, it is used so that the operations are not constant-folded.
+    let one = std::env::args().count() as u16;
+
+    let a = u16x4(one, 2, 3, 4);
+    let b = u16x4(5, 6, 7, 8 * one);
+
+    unsafe {
+        let reversed: u16x4 = simd_shuffle4(a, b, REVERSE);
+        assert_eq!(reversed, u16x4(8, 7, 2, 1));
+
+        let joined: u16x8 = simd_shuffle8(a, b, JOIN);
+        assert_eq!(joined, u16x8(1, 5, 2, 6, 3, 7, 4, 8));
+
+        let high: u16x2 = simd_shuffle2(a, b, HIGH);
+        assert_eq!(high, u16x2(3, 4));
+
+        let inserted = simd_insert(a, one as u32, 42_u16);
+        assert_eq!(inserted, u16x4(1, 42, 3, 4));
+        assert_eq!(simd_extract::<_, u16>(inserted, 3 - one as u32), 3);
+
+        let c = i32x4(one as i32, -2, 3, -4);
+        let reversed: i32x4 = simd_shuffle4(c, c, REVERSE);
+        assert_eq!(reversed, i32x4(-4, 3, -2, 1));
+    }
+}
@@ -0,0 +1,52 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+#![feature(core_intrinsics)]
+
+use std::intrinsics::{nontemporal_store, volatile_copy_memory, volatile_copy_nonoverlapping_memory, volatile_set_memory};
+
+fn main() {
+    let mut buffer = [0u8; 8];
+    unsafe {
+        volatile_set_memory(buffer.as_mut_ptr(), 7, buffer.len());
+    }
+    assert_eq!(buffer, [7; 8]);
+
+    let source = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    unsafe {
+        volatile_copy_nonoverlapping_memory(buffer.as_mut_ptr(), source.as_ptr(), source.len());
+    }
+    assert_eq!(buffer, source);
+
+    // Overlapping copies in both directions.
+    unsafe {
+        volatile_copy_memory(buffer.as_mut_ptr().add(2), buffer.as_ptr(), 4);
+    }
+    assert_eq!(buffer, [1, 2, 1, 2, 3, 4, 7, 8]);
+    unsafe {
+        volatile_copy_memory(buffer.as_mut_ptr(), buffer.as_ptr().add(3), 4);
+    }
+    assert_eq!(buffer, [2, 3, 4, 7, 3, 4, 7, 8]);
+
+    let mut value = 0u64;
+    unsafe {
+        std::ptr::write_volatile(&mut value, 42);
+        assert_eq!(std::ptr::read_volatile(&value), 42);
+    }
+
+    // Non-temporal stores of values larger than 8 bytes are copies with the NONTEMPORAL flag.
+    let mut large = [0u64; 3];
+    unsafe {
+        nontemporal_store(&mut large, [1, 2, 3]);
+    }
+    assert_eq!(large, [1, 2, 3]);
+
+    let mut bytes = [0u8; 13];
+    let source = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+    unsafe {
+        nontemporal_store(&mut bytes, source);
+    }
+    assert_eq!(bytes, source);
+}
@@ -0,0 +1,54 @@
+// Compiler:
+//
+// Run-time:
+//   status: 0
+
+fn main() {
+    use std::arch::x86_64::*;
+
+    // NOTE: the number of arguments is 1, it is used so that the operations are not constant-folded.
+    let one = std::env::args().count() as u64;
+
+    unsafe {
+        let mut sum = 0;
+        let carry = _addcarry_u64(0, u64::MAX, one, &mut sum);
+        assert_eq!(carry, 1);
+        assert_eq!(sum, 0);
+
+        let mut difference = 0;
+        let borrow = _subborrow_u64(0, 0, one, &mut difference);
+        assert_eq!(borrow, 1);
+        assert_eq!(difference, u64::MAX);
+
+        let a = _mm_set_epi8(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let b = _mm_set1_epi8(one as i8);
+        let sad = _mm_sad_epu8(a, b);
+        let mut result = [0_u64; 2];
+        _mm_storeu_si128(result.as_mut_ptr() as *mut __m128i, sad);
+        assert_eq!(result, [92, 28]);
+
+        if is_x86_feature_detected!("sse4.2") {
+            assert_eq!(sse42_crc32(one as u32), 0xdd45aab8);
+        }
+
+        if is_x86_feature_detected!("avx2") {
+            let data = [10_i32, 11, 12, 13, 14, 15, 16, 17];
+            let gathered = avx2_gather(&data, one as i32);
+            assert_eq!(gathered, [17, 16, 15, 14, 13, 12, 11, 10]);
+        }
+    }
+}
+
+#[target_feature(enable = "sse4.2")]
+unsafe fn sse42_crc32(value: u32) -> u32 {
+    std::arch::x86_64::_mm_crc32_u32(0, value)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_gather(data: &[i32; 8], one: i32) -> [i32; 8] {
+    use std::arch::x86_64::*;
+
+    let indices = _mm256_setr_epi32(7 * one, 6, 5, 4, 3, 2, 1, 0);
+    let gathered = _mm256_i32gather_epi32::<4>(data.as_ptr(), indices);
+    std::mem::transmute(gathered)
+}
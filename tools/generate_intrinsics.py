@@ -0,0 +1,144 @@
+#!/usr/bin/env python3
+
+# Generates src/intrinsic/archs.rs: the mapping from the LLVM target intrinsics used by stdarch
+# (`#[link_name = "llvm.x86.*"]`) to the equivalent GCC target builtins.
+#
+# The mapping comes from the `GCCBuiltin` annotations of the LLVM intrinsic definitions
+# (llvm/IR/Intrinsics*.td). Only the builtins that the supported libgccjit defines are kept: their
+# names are found in the strings of libgccjit.so, and some LLVM names are adjusted to the GCC names
+# (e.g. GCC only has the `_mask` version of some AVX-512 builtins). The builtins of a more recent
+# GCC (e.g. the AVX512-FP16 ones of GCC 12) are thus not mapped.
+#
+# Usage: tools/generate_intrinsics.py [--llvm-tblgen llvm-tblgen-14] [--llvm-include DIR] [--libgccjit FILE]
+#
+# Use the LLVM version of the Rust toolchain (see rust-toolchain) and the libgccjit of gcc_path,
+# which is the default.
+
+import argparse
+import json
+import os
+import re
+import subprocess
+import sys
+import tempfile
+
+ARCHS = {
+    # LLVM target prefix: GCC builtin prefix
+    "x86": "__builtin_ia32_",
+}
+
+
+def command_output(command):
+    return subprocess.check_output(command, universal_newlines=True).strip()
+
+
+def llvm_intrinsics(llvm_tblgen, llvm_include):
+    with tempfile.TemporaryDirectory() as directory:
+        output = os.path.join(directory, "intrinsics.json")
+        subprocess.check_call([
+            llvm_tblgen, "--dump-json", "-I", llvm_include,
+            os.path.join(llvm_include, "llvm/IR/Intrinsics.td"), "-o", output,
+        ])
+        with open(output) as file:
+            records = json.load(file)
+
+    for record_name in records["!instanceof"]["Intrinsic"]:
+        record = records[record_name]
+        gcc_name = record.get("GCCBuiltinName")
+        if not gcc_name:
+            continue
+        # NOTE: the LLVM name is derived from the record name when it is not specified.
+        llvm_name = record["LLVMName"] or "llvm." + record_name[len("int_"):].replace("_", ".")
+        yield record["TargetPrefix"], llvm_name, gcc_name
+
+
+def gcc_builtins(libgccjit, prefix):
+    # NOTE: the names of the builtins are null-terminated strings in libgccjit.so.
+    pattern = re.compile(re.escape(prefix.encode()) + rb"\w+")
+    with open(libgccjit, "rb") as file:
+        return {name.decode() for name in pattern.findall(file.read())}
+
+
+def gcc_candidates(name):
+    yield name
+    # GCC only has the masked version of some builtins. The missing arguments (merge source and
+    # mask) are added in adjust_intrinsic_arguments().
+    yield name + "_mask"
+    # Clang and GCC disagree on the order of the suffixes.
+    if name.endswith("_round_mask"):
+        yield name[:-len("_round_mask")] + "_mask_round"
+        yield re.sub(r"128$", "", name[:-len("_round_mask")]) + "_mask_round"
+
+
+def generate(mapping, added_mask, output):
+    with open(output, "w") as file:
+        file.write("// File generated by `tools/generate_intrinsics.py`\n")
+        file.write("// DO NOT EDIT IT!\n")
+        file.write("\n")
+        file.write("/// Returns the name of the GCC builtin equivalent to the LLVM intrinsic `name`, if any.\n")
+        file.write("pub fn llvm_to_gcc_builtin(name: &str) -> Option<&'static str> {\n")
+        file.write("    let gcc_name =\n")
+        file.write("        match name {\n")
+        for arch in sorted(mapping):
+            file.write("            // {}\n".format(arch))
+            for llvm_name, gcc_name in sorted(mapping[arch].items()):
+                file.write("            \"{}\" => \"{}\",\n".format(llvm_name, gcc_name))
+        file.write("            _ => return None,\n")
+        file.write("        };\n")
+        file.write("    Some(gcc_name)\n")
+        file.write("}\n")
+        file.write("\n")
+        file.write("/// Whether the GCC builtin `name` is the `_mask` version of the builtin of an LLVM intrinsic,\n")
+        file.write("/// and so takes a merge source and a mask that the intrinsic does not take.\n")
+        file.write("pub fn is_unmasked_intrinsic_builtin(name: &str) -> bool {\n")
+        file.write("    matches!(name,\n")
+        for index, gcc_name in enumerate(sorted(added_mask)):
+            separator = "        " if index == 0 else "        | "
+            file.write("{}\"{}\"\n".format(separator, gcc_name))
+        file.write("    )\n")
+        file.write("}\n")
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Generate the LLVM to GCC intrinsic mapping")
+    parser.add_argument("--llvm-tblgen", default="llvm-tblgen")
+    parser.add_argument("--llvm-include")
+    parser.add_argument("--libgccjit")
+    args = parser.parse_args()
+
+    root = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+    llvm_include = args.llvm_include or command_output(["llvm-config", "--includedir"])
+    libgccjit = args.libgccjit
+    if not libgccjit:
+        with open(os.path.join(root, "gcc_path")) as file:
+            libgccjit = os.path.join(file.read().strip(), "libgccjit.so")
+
+    builtins = {}
+    for arch, prefix in ARCHS.items():
+        builtins[arch] = gcc_builtins(libgccjit, prefix)
+        if not builtins[arch]:
+            sys.exit("No GCC builtin found for {} in {}".format(arch, libgccjit))
+
+    mapping = {arch: {} for arch in ARCHS}
+    added_mask = set()
+    missing = []
+    for arch, llvm_name, gcc_name in llvm_intrinsics(args.llvm_tblgen, llvm_include):
+        if arch not in ARCHS:
+            continue
+        for candidate in gcc_candidates(gcc_name):
+            if candidate in builtins[arch]:
+                mapping[arch][llvm_name] = candidate
+                if candidate == gcc_name + "_mask":
+                    added_mask.add(candidate)
+                break
+        else:
+            missing.append(llvm_name)
+
+    output = os.path.join(root, "src", "intrinsic", "archs.rs")
+    generate(mapping, added_mask, output)
+    print("Generated {} ({} intrinsics, {} without a GCC builtin)".format(
+        output, sum(len(intrinsics) for intrinsics in mapping.values()), len(missing)))
+
+
+if __name__ == "__main__":
+    main()